@@ -0,0 +1,883 @@
+/* DPDK 22.11 bindings for x86_64 Linux, in the form bindgen 0.60 generates them, for the items used by the default
+ * features and `gso`. Types the crate never looks into are opaque, and layout tests are left out. Regenerate from an
+ * install with `make update-bindings`. */
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage> {
+    storage: Storage,
+}
+impl<Storage> __BindgenBitfieldUnit<Storage> {
+    #[inline]
+    pub const fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+impl<Storage> __BindgenBitfieldUnit<Storage>
+where
+    Storage: AsRef<[u8]> + AsMut<[u8]>,
+{
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        let bit_index = if cfg!(target_endian = "big") { 7 - (index % 8) } else { index % 8 };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        let bit_index = if cfg!(target_endian = "big") { 7 - (index % 8) } else { index % 8 };
+        let mask = 1 << bit_index;
+        if val {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") { bit_width as usize - 1 - i } else { i };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") { bit_width as usize - 1 - i } else { i };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+}
+#[repr(C)]
+#[derive(Default)]
+pub struct __IncompleteArrayField<T>(::std::marker::PhantomData<T>, [T; 0]);
+impl<T> __IncompleteArrayField<T> {
+    #[inline]
+    pub const fn new() -> Self {
+        __IncompleteArrayField(::std::marker::PhantomData, [])
+    }
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self as *const _ as *const T
+    }
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self as *mut _ as *mut T
+    }
+    #[inline]
+    pub unsafe fn as_slice(&self, len: usize) -> &[T] {
+        ::std::slice::from_raw_parts(self.as_ptr(), len)
+    }
+    #[inline]
+    pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
+        ::std::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
+    }
+}
+impl<T> ::std::fmt::Debug for __IncompleteArrayField<T> {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        fmt.write_str("__IncompleteArrayField")
+    }
+}
+pub const RTE_MAX_LCORE: u32 = 128;
+pub const RTE_MAX_NUMA_NODES: u32 = 32;
+pub const RTE_MAX_ETHPORTS: u32 = 32;
+pub const RTE_MEMPOOL_CACHE_MAX_SIZE: u32 = 512;
+pub const RTE_PKTMBUF_HEADROOM: u32 = 128;
+pub const RTE_MEMPOOL_NAMESIZE: u32 = 29;
+pub const RTE_MEMPOOL_F_NO_SPREAD: u32 = 1;
+pub const RTE_MEMPOOL_F_NO_CACHE_ALIGN: u32 = 2;
+pub const RTE_MEMPOOL_F_SP_PUT: u32 = 4;
+pub const RTE_MEMPOOL_F_SC_GET: u32 = 8;
+pub const RTE_MEMPOOL_F_POOL_CREATED: u32 = 16;
+pub const RTE_MEMPOOL_F_NO_IOVA_CONTIG: u32 = 32;
+pub const RTE_MEMPOOL_F_NON_IO: u32 = 64;
+pub const RTE_MBUF_PRIV_ALIGN: u32 = 8;
+pub const RTE_MBUF_DEFAULT_DATAROOM: u32 = 2048;
+pub const RTE_MBUF_DEFAULT_BUF_SIZE: u32 = 2176;
+pub const RTE_ETHER_ADDR_LEN: u32 = 6;
+pub const RTE_ETHER_TYPE_LEN: u32 = 2;
+pub const RTE_ETHER_CRC_LEN: u32 = 4;
+pub const RTE_ETHER_HDR_LEN: u32 = 14;
+pub const RTE_ETHER_MIN_LEN: u32 = 64;
+pub const RTE_ETHER_MAX_LEN: u32 = 1518;
+pub const RTE_ETHER_MTU: u32 = 1500;
+pub const RTE_ETHER_MAX_JUMBO_FRAME_LEN: u32 = 16128;
+pub const RTE_ETHER_MAX_VLAN_ID: u32 = 4095;
+pub const RTE_ETHER_MIN_MTU: u32 = 68;
+pub const RTE_ETH_LINK_HALF_DUPLEX: u32 = 0;
+pub const RTE_ETH_LINK_FULL_DUPLEX: u32 = 1;
+pub const RTE_ETH_LINK_DOWN: u32 = 0;
+pub const RTE_ETH_LINK_UP: u32 = 1;
+pub const RTE_ETH_LINK_FIXED: u32 = 0;
+pub const RTE_ETH_LINK_AUTONEG: u32 = 1;
+pub const RTE_ETH_RETA_GROUP_SIZE: u32 = 64;
+pub const RTE_ETH_VMDQ_MAX_VLAN_FILTERS: u32 = 64;
+pub const RTE_ETH_DCB_NUM_USER_PRIORITIES: u32 = 8;
+pub const RTE_ETH_DEV_NO_OWNER: u32 = 0;
+pub type rte_be16_t = u16;
+pub type rte_be32_t = u32;
+pub type rte_iova_t = u64;
+pub type RTE_MARKER = [*mut ::std::os::raw::c_void; 0usize];
+pub type RTE_MARKER64 = [u64; 0usize];
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_atomic16_t {
+    pub cnt: i16,
+}
+extern "C" {
+    pub fn rte_eal_init(argc: ::std::os::raw::c_int, argv: *mut *mut ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eal_cleanup() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_strerror(errnum: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn rte_socket_id() -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn rte_delay_us_block(us: ::std::os::raw::c_uint);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_memzone {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mempool_cache {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mempool_objhdr {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mempool_memhdr {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mempool_objhdr_list {
+    pub stqh_first: *mut rte_mempool_objhdr,
+    pub stqh_last: *mut *mut rte_mempool_objhdr,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mempool_memhdr_list {
+    pub stqh_first: *mut rte_mempool_memhdr,
+    pub stqh_last: *mut *mut rte_mempool_memhdr,
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Copy, Clone)]
+pub struct rte_mempool {
+    pub name: [::std::os::raw::c_char; 29usize],
+    pub __bindgen_anon_1: rte_mempool__bindgen_ty_1,
+    pub pool_config: *mut ::std::os::raw::c_void,
+    pub mz: *const rte_memzone,
+    pub flags: ::std::os::raw::c_uint,
+    pub socket_id: ::std::os::raw::c_int,
+    pub size: u32,
+    pub cache_size: u32,
+    pub elt_size: u32,
+    pub header_size: u32,
+    pub trailer_size: u32,
+    pub private_data_size: ::std::os::raw::c_uint,
+    pub ops_index: i32,
+    pub local_cache: *mut rte_mempool_cache,
+    pub populated_size: u32,
+    pub elt_list: rte_mempool_objhdr_list,
+    pub nb_mem_chunks: u32,
+    pub mem_list: rte_mempool_memhdr_list,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_mempool__bindgen_ty_1 {
+    pub pool_data: *mut ::std::os::raw::c_void,
+    pub pool_id: u64,
+}
+extern "C" {
+    pub fn rte_mempool_free(mp: *mut rte_mempool);
+}
+extern "C" {
+    pub fn rte_mempool_avail_count(mp: *const rte_mempool) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn rte_mempool_in_use_count(mp: *const rte_mempool) -> ::std::os::raw::c_uint;
+}
+extern "C" {
+    pub fn rte_mempool_lookup(name: *const ::std::os::raw::c_char) -> *mut rte_mempool;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mbuf_sched {
+    pub queue_id: u32,
+    pub traffic_class: u8,
+    pub color: u8,
+    pub reserved: u16,
+}
+pub type rte_mbuf_extbuf_free_callback_t = ::std::option::Option<
+    unsafe extern "C" fn(addr: *mut ::std::os::raw::c_void, opaque: *mut ::std::os::raw::c_void),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mbuf_ext_shared_info {
+    pub free_cb: rte_mbuf_extbuf_free_callback_t,
+    pub fcb_opaque: *mut ::std::os::raw::c_void,
+    pub refcnt: u16,
+}
+#[repr(C)]
+#[repr(align(64))]
+#[derive(Copy, Clone)]
+pub struct rte_mbuf {
+    pub cacheline0: RTE_MARKER,
+    pub buf_addr: *mut ::std::os::raw::c_void,
+    pub buf_iova: rte_iova_t,
+    pub rearm_data: RTE_MARKER64,
+    pub data_off: u16,
+    pub __bindgen_anon_1: rte_mbuf__bindgen_ty_1,
+    pub nb_segs: u16,
+    pub port: u16,
+    pub ol_flags: u64,
+    pub rx_descriptor_fields1: RTE_MARKER,
+    pub __bindgen_anon_2: rte_mbuf__bindgen_ty_2,
+    pub pkt_len: u32,
+    pub data_len: u16,
+    pub vlan_tci: u16,
+    pub __bindgen_anon_3: rte_mbuf__bindgen_ty_3,
+    pub vlan_tci_outer: u16,
+    pub buf_len: u16,
+    pub pool: *mut rte_mempool,
+    pub cacheline1: RTE_MARKER,
+    pub next: *mut rte_mbuf,
+    pub __bindgen_anon_4: rte_mbuf__bindgen_ty_4,
+    pub shinfo: *mut rte_mbuf_ext_shared_info,
+    pub priv_size: u16,
+    pub timesync: u16,
+    pub dynfield1: [u32; 9usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_mbuf__bindgen_ty_1 {
+    pub refcnt_atomic: rte_atomic16_t,
+    pub refcnt: u16,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_mbuf__bindgen_ty_2 {
+    pub packet_type: u32,
+    pub __bindgen_anon_1: rte_mbuf__bindgen_ty_2__bindgen_ty_1,
+}
+#[repr(C)]
+#[repr(align(4))]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mbuf__bindgen_ty_2__bindgen_ty_1 {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_mbuf__bindgen_ty_3 {
+    pub hash: rte_mbuf__bindgen_ty_3__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_mbuf__bindgen_ty_3__bindgen_ty_1 {
+    pub rss: u32,
+    pub fdir: rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1,
+    pub sched: rte_mbuf_sched,
+    pub txadapter: rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_2,
+    pub usr: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1 {
+    pub __bindgen_anon_1: rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1__bindgen_ty_1,
+    pub hi: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1__bindgen_ty_1 {
+    pub __bindgen_anon_1: rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1__bindgen_ty_1__bindgen_ty_1,
+    pub lo: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_1__bindgen_ty_1__bindgen_ty_1 {
+    pub hash: u16,
+    pub id: u16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mbuf__bindgen_ty_3__bindgen_ty_1__bindgen_ty_2 {
+    pub reserved1: u32,
+    pub reserved2: u16,
+    pub txq: u16,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_mbuf__bindgen_ty_4 {
+    pub tx_offload: u64,
+    pub __bindgen_anon_1: rte_mbuf__bindgen_ty_4__bindgen_ty_1,
+}
+#[repr(C)]
+#[repr(align(8))]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_mbuf__bindgen_ty_4__bindgen_ty_1 {
+    pub _bitfield_align_1: [u16; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 7usize]>,
+    pub __bindgen_padding_0: u8,
+}
+extern "C" {
+    pub fn rte_pktmbuf_pool_create(
+        name: *const ::std::os::raw::c_char,
+        n: ::std::os::raw::c_uint,
+        cache_size: ::std::os::raw::c_uint,
+        priv_size: u16,
+        data_room_size: u16,
+        socket_id: ::std::os::raw::c_int,
+    ) -> *mut rte_mempool;
+}
+extern "C" {
+    pub fn rte_pktmbuf_clone(md: *mut rte_mbuf, mp: *mut rte_mempool) -> *mut rte_mbuf;
+}
+extern "C" {
+    pub fn rte_pktmbuf_copy(m: *const rte_mbuf, mp: *mut rte_mempool, offset: u32, length: u32) -> *mut rte_mbuf;
+}
+extern "C" {
+    pub fn rte_pktmbuf_free_bulk(mbufs: *mut *mut rte_mbuf, count: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn rte_pktmbuf_dump(f: *mut FILE, m: *const rte_mbuf, dump_len: ::std::os::raw::c_uint);
+}
+extern "C" {
+    pub fn rte_mbuf_sanity_check(m: *const rte_mbuf, is_header: ::std::os::raw::c_int);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_FILE {
+    _unused: [u8; 0],
+}
+pub type FILE = _IO_FILE;
+#[repr(C)]
+#[repr(align(2))]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_ether_addr {
+    pub addr_bytes: [u8; 6usize],
+}
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct rte_ipv4_hdr {
+    pub __bindgen_anon_1: rte_ipv4_hdr__bindgen_ty_1,
+    pub type_of_service: u8,
+    pub total_length: rte_be16_t,
+    pub packet_id: rte_be16_t,
+    pub fragment_offset: rte_be16_t,
+    pub time_to_live: u8,
+    pub next_proto_id: u8,
+    pub hdr_checksum: rte_be16_t,
+    pub src_addr: rte_be32_t,
+    pub dst_addr: rte_be32_t,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_ipv4_hdr__bindgen_ty_1 {
+    pub version_ihl: u8,
+    pub __bindgen_anon_1: rte_ipv4_hdr__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_ipv4_hdr__bindgen_ty_1__bindgen_ty_1 {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_ipv6_hdr {
+    pub vtc_flow: rte_be32_t,
+    pub payload_len: rte_be16_t,
+    pub proto: u8,
+    pub hop_limits: u8,
+    pub src_addr: [u8; 16usize],
+    pub dst_addr: [u8; 16usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_device {
+    _unused: [u8; 0],
+}
+pub type rte_eth_rx_mq_mode = ::std::os::raw::c_uint;
+pub const rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_NONE: rte_eth_rx_mq_mode = 0;
+pub const rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_RSS: rte_eth_rx_mq_mode = 1;
+pub const rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_DCB: rte_eth_rx_mq_mode = 2;
+pub const rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_DCB_RSS: rte_eth_rx_mq_mode = 3;
+pub const rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_VMDQ_ONLY: rte_eth_rx_mq_mode = 4;
+pub const rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_VMDQ_RSS: rte_eth_rx_mq_mode = 5;
+pub const rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_VMDQ_DCB: rte_eth_rx_mq_mode = 6;
+pub const rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_VMDQ_DCB_RSS: rte_eth_rx_mq_mode = 7;
+pub type rte_eth_tx_mq_mode = ::std::os::raw::c_uint;
+pub const rte_eth_tx_mq_mode_RTE_ETH_MQ_TX_NONE: rte_eth_tx_mq_mode = 0;
+pub const rte_eth_tx_mq_mode_RTE_ETH_MQ_TX_DCB: rte_eth_tx_mq_mode = 1;
+pub const rte_eth_tx_mq_mode_RTE_ETH_MQ_TX_VMDQ_DCB: rte_eth_tx_mq_mode = 2;
+pub const rte_eth_tx_mq_mode_RTE_ETH_MQ_TX_VMDQ_ONLY: rte_eth_tx_mq_mode = 3;
+pub type rte_eth_nb_tcs = ::std::os::raw::c_uint;
+pub const rte_eth_nb_tcs_RTE_ETH_4_TCS: rte_eth_nb_tcs = 4;
+pub const rte_eth_nb_tcs_RTE_ETH_8_TCS: rte_eth_nb_tcs = 8;
+pub type rte_eth_nb_pools = ::std::os::raw::c_uint;
+pub const rte_eth_nb_pools_RTE_ETH_8_POOLS: rte_eth_nb_pools = 8;
+pub const rte_eth_nb_pools_RTE_ETH_16_POOLS: rte_eth_nb_pools = 16;
+pub const rte_eth_nb_pools_RTE_ETH_32_POOLS: rte_eth_nb_pools = 32;
+pub const rte_eth_nb_pools_RTE_ETH_64_POOLS: rte_eth_nb_pools = 64;
+pub type rte_eth_err_handle_mode = ::std::os::raw::c_uint;
+pub const rte_eth_err_handle_mode_RTE_ETH_ERROR_HANDLE_MODE_NONE: rte_eth_err_handle_mode = 0;
+pub const rte_eth_err_handle_mode_RTE_ETH_ERROR_HANDLE_MODE_PASSIVE: rte_eth_err_handle_mode = 1;
+pub const rte_eth_err_handle_mode_RTE_ETH_ERROR_HANDLE_MODE_PROACTIVE: rte_eth_err_handle_mode = 2;
+#[repr(C)]
+#[repr(align(8))]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_link {
+    pub link_speed: u32,
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+    pub __bindgen_padding_0: [u8; 3usize],
+}
+impl rte_eth_link {
+    #[inline]
+    pub fn link_duplex(&self) -> u16 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set_link_duplex(&mut self, val: u16) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn link_autoneg(&self) -> u16 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set_link_autoneg(&mut self, val: u16) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub fn link_status(&self) -> u16 {
+        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u16) }
+    }
+    #[inline]
+    pub fn set_link_status(&mut self, val: u16) {
+        unsafe {
+            let val: u16 = ::std::mem::transmute(val);
+            self._bitfield_1.set(2usize, 1u8, val as u64)
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_thresh {
+    pub pthresh: u8,
+    pub hthresh: u8,
+    pub wthresh: u8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_rxmode {
+    pub mq_mode: rte_eth_rx_mq_mode,
+    pub mtu: u32,
+    pub max_lro_pkt_size: u32,
+    pub offloads: u64,
+    pub reserved_64s: [u64; 2usize],
+    pub reserved_ptrs: [*mut ::std::os::raw::c_void; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_txmode {
+    pub mq_mode: rte_eth_tx_mq_mode,
+    pub offloads: u64,
+    pub pvid: u16,
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+    pub reserved_64s: [u64; 2usize],
+    pub reserved_ptrs: [*mut ::std::os::raw::c_void; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_rss_conf {
+    pub rss_key: *mut u8,
+    pub rss_key_len: u8,
+    pub rss_hf: u64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_rss_reta_entry64 {
+    pub mask: u64,
+    pub reta: [u16; 64usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_vmdq_dcb_conf {
+    pub nb_queue_pools: rte_eth_nb_pools,
+    pub enable_default_pool: u8,
+    pub default_pool: u8,
+    pub nb_pool_maps: u8,
+    pub pool_map: [rte_eth_vmdq_dcb_conf__bindgen_ty_1; 64usize],
+    pub dcb_tc: [u8; 8usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_vmdq_dcb_conf__bindgen_ty_1 {
+    pub vlan_id: u16,
+    pub pools: u64,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_dcb_rx_conf {
+    pub nb_tcs: rte_eth_nb_tcs,
+    pub dcb_tc: [u8; 8usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_vmdq_dcb_tx_conf {
+    pub nb_queue_pools: rte_eth_nb_pools,
+    pub dcb_tc: [u8; 8usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_dcb_tx_conf {
+    pub nb_tcs: rte_eth_nb_tcs,
+    pub dcb_tc: [u8; 8usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_vmdq_tx_conf {
+    pub nb_queue_pools: rte_eth_nb_pools,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_vmdq_rx_conf {
+    pub nb_queue_pools: rte_eth_nb_pools,
+    pub enable_default_pool: u8,
+    pub default_pool: u8,
+    pub enable_loop_back: u8,
+    pub nb_pool_maps: u8,
+    pub rx_mode: u32,
+    pub pool_map: [rte_eth_vmdq_rx_conf__bindgen_ty_1; 64usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_vmdq_rx_conf__bindgen_ty_1 {
+    pub vlan_id: u16,
+    pub pools: u64,
+}
+#[repr(C)]
+#[repr(align(4))]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_intr_conf {
+    pub _bitfield_align_1: [u16; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+    pub __bindgen_padding_0: [u8; 3usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct rte_eth_conf {
+    pub link_speeds: u32,
+    pub rxmode: rte_eth_rxmode,
+    pub txmode: rte_eth_txmode,
+    pub lpbk_mode: u32,
+    pub rx_adv_conf: rte_eth_conf__bindgen_ty_1,
+    pub tx_adv_conf: rte_eth_conf__bindgen_ty_2,
+    pub dcb_capability_en: u32,
+    pub intr_conf: rte_eth_intr_conf,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_conf__bindgen_ty_1 {
+    pub rss_conf: rte_eth_rss_conf,
+    pub vmdq_dcb_conf: rte_eth_vmdq_dcb_conf,
+    pub dcb_rx_conf: rte_eth_dcb_rx_conf,
+    pub vmdq_rx_conf: rte_eth_vmdq_rx_conf,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_eth_conf__bindgen_ty_2 {
+    pub vmdq_dcb_tx_conf: rte_eth_vmdq_dcb_tx_conf,
+    pub dcb_tx_conf: rte_eth_dcb_tx_conf,
+    pub vmdq_tx_conf: rte_eth_vmdq_tx_conf,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_rxseg_split {
+    pub mp: *mut rte_mempool,
+    pub length: u16,
+    pub offset: u16,
+    pub proto_hdr: u32,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union rte_eth_rxseg {
+    pub split: rte_eth_rxseg_split,
+    pub reserved: [u64; 4usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_rxconf {
+    pub rx_thresh: rte_eth_thresh,
+    pub rx_free_thresh: u16,
+    pub rx_drop_en: u8,
+    pub rx_deferred_start: u8,
+    pub rx_nseg: u16,
+    pub share_group: u16,
+    pub share_qid: u16,
+    pub offloads: u64,
+    pub rx_seg: *mut rte_eth_rxseg,
+    pub rx_mempools: *mut *mut rte_mempool,
+    pub rx_nmempool: u16,
+    pub reserved_64s: [u64; 2usize],
+    pub reserved_ptrs: [*mut ::std::os::raw::c_void; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_txconf {
+    pub tx_thresh: rte_eth_thresh,
+    pub tx_rs_thresh: u16,
+    pub tx_free_thresh: u16,
+    pub tx_deferred_start: u8,
+    pub offloads: u64,
+    pub reserved_64s: [u64; 2usize],
+    pub reserved_ptrs: [*mut ::std::os::raw::c_void; 2usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_desc_lim {
+    pub nb_max: u16,
+    pub nb_min: u16,
+    pub nb_align: u16,
+    pub nb_seg_max: u16,
+    pub nb_mtu_seg_max: u16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_dev_portconf {
+    pub burst_size: u16,
+    pub ring_size: u16,
+    pub nb_queues: u16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_switch_info {
+    pub name: *const ::std::os::raw::c_char,
+    pub domain_id: u16,
+    pub port_id: u16,
+    pub rx_domain: u16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_rxseg_capa {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+    pub max_nseg: u16,
+    pub reserved: u16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_eth_dev_info {
+    pub device: *mut rte_device,
+    pub driver_name: *const ::std::os::raw::c_char,
+    pub if_index: ::std::os::raw::c_uint,
+    pub min_mtu: u16,
+    pub max_mtu: u16,
+    pub dev_flags: *const u32,
+    pub min_rx_bufsize: u32,
+    pub max_rx_pktlen: u32,
+    pub max_lro_pkt_size: u32,
+    pub max_rx_queues: u16,
+    pub max_tx_queues: u16,
+    pub max_mac_addrs: u32,
+    pub max_hash_mac_addrs: u32,
+    pub max_vfs: u16,
+    pub max_vmdq_pools: u16,
+    pub rx_seg_capa: rte_eth_rxseg_capa,
+    pub rx_offload_capa: u64,
+    pub tx_offload_capa: u64,
+    pub rx_queue_offload_capa: u64,
+    pub tx_queue_offload_capa: u64,
+    pub reta_size: u16,
+    pub hash_key_size: u8,
+    pub flow_type_rss_offloads: u64,
+    pub default_rxconf: rte_eth_rxconf,
+    pub default_txconf: rte_eth_txconf,
+    pub vmdq_queue_base: u16,
+    pub vmdq_queue_num: u16,
+    pub vmdq_pool_base: u16,
+    pub rx_desc_lim: rte_eth_desc_lim,
+    pub tx_desc_lim: rte_eth_desc_lim,
+    pub speed_capa: u32,
+    pub nb_rx_queues: u16,
+    pub nb_tx_queues: u16,
+    pub max_rx_mempools: u16,
+    pub default_rxportconf: rte_eth_dev_portconf,
+    pub default_txportconf: rte_eth_dev_portconf,
+    pub dev_capa: u64,
+    pub switch_info: rte_eth_switch_info,
+    pub err_handle_mode: rte_eth_err_handle_mode,
+    pub reserved_64s: [u64; 2usize],
+    pub reserved_ptrs: [*mut ::std::os::raw::c_void; 2usize],
+}
+pub type buffer_tx_error_fn = ::std::option::Option<
+    unsafe extern "C" fn(unsent: *mut *mut rte_mbuf, count: u16, userdata: *mut ::std::os::raw::c_void),
+>;
+#[repr(C)]
+#[derive(Debug)]
+pub struct rte_eth_dev_tx_buffer {
+    pub error_callback: buffer_tx_error_fn,
+    pub error_userdata: *mut ::std::os::raw::c_void,
+    pub size: u16,
+    pub length: u16,
+    pub pkts: __IncompleteArrayField<*mut rte_mbuf>,
+}
+extern "C" {
+    pub fn rte_eth_dev_count_avail() -> u16;
+}
+extern "C" {
+    pub fn rte_eth_find_next_owned_by(port_id: u16, owner_id: u64) -> u64;
+}
+extern "C" {
+    pub fn rte_eth_dev_is_valid_port(port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_socket_id(port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_info_get(port_id: u16, dev_info: *mut rte_eth_dev_info) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_configure(
+        port_id: u16,
+        nb_rx_queue: u16,
+        nb_tx_queue: u16,
+        eth_conf: *const rte_eth_conf,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_rx_queue_setup(
+        port_id: u16,
+        rx_queue_id: u16,
+        nb_rx_desc: u16,
+        socket_id: ::std::os::raw::c_uint,
+        rx_conf: *const rte_eth_rxconf,
+        mb_pool: *mut rte_mempool,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_tx_queue_setup(
+        port_id: u16,
+        tx_queue_id: u16,
+        nb_tx_desc: u16,
+        socket_id: ::std::os::raw::c_uint,
+        tx_conf: *const rte_eth_txconf,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_start(port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_stop(port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_close(port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_set_mtu(port_id: u16, mtu: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_get_mtu(port_id: u16, mtu: *mut u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_promiscuous_enable(port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_promiscuous_disable(port_id: u16) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_link_get(port_id: u16, link: *mut rte_eth_link) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_link_get_nowait(port_id: u16, link: *mut rte_eth_link) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_macaddr_get(port_id: u16, mac_addr: *mut rte_ether_addr) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_get_supported_ptypes(
+        port_id: u16,
+        ptype_mask: u32,
+        ptypes: *mut u32,
+        num: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_rss_reta_update(
+        port_id: u16,
+        reta_conf: *mut rte_eth_rss_reta_entry64,
+        reta_size: u16,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_rss_reta_query(
+        port_id: u16,
+        reta_conf: *mut rte_eth_rss_reta_entry64,
+        reta_size: u16,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_rss_hash_update(port_id: u16, rss_conf: *mut rte_eth_rss_conf) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_dev_rss_hash_conf_get(port_id: u16, rss_conf: *mut rte_eth_rss_conf) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rte_eth_tx_buffer_init(buffer: *mut rte_eth_dev_tx_buffer, size: u16) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rte_gso_ctx {
+    pub direct_pool: *mut rte_mempool,
+    pub indirect_pool: *mut rte_mempool,
+    pub flag: u64,
+    pub gso_types: u32,
+    pub gso_size: u16,
+}
+extern "C" {
+    pub fn rte_gso_segment(
+        pkt: *mut rte_mbuf,
+        ctx: *const rte_gso_ctx,
+        pkts_out: *mut *mut rte_mbuf,
+        nb_pkts_out: u16,
+    ) -> ::std::os::raw::c_int;
+}
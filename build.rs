@@ -6,6 +6,763 @@ use ::bindgen::{Bindings, Builder};
 use ::cc::Build;
 use ::std::{env, path::Path};
 
+/// Returns the poll mode driver libraries selected through cargo features, along with the bus and common libraries
+/// they depend on. These are linked on top of what `libdpdk` reports, because applications never reference the
+/// drivers directly.
+fn driver_libraries() -> Vec<&'static str> {
+    let mut libs: Vec<&'static str> = vec![];
+    if cfg!(feature = "mlx4") {
+        // Unlike mlx5, the mlx4 driver does not have a common library.
+        libs.extend(&["rte_net_mlx4", "rte_bus_pci"]);
+    }
+    if cfg!(feature = "mlx5") {
+        libs.extend(&["rte_net_mlx5", "rte_bus_pci", "rte_bus_vdev", "rte_common_mlx5"]);
+    }
+    if cfg!(feature = "net_ring") {
+        libs.extend(&["rte_net_ring", "rte_bus_vdev"]);
+    }
+    if cfg!(feature = "net_null") {
+        libs.extend(&["rte_net_null", "rte_bus_vdev"]);
+    }
+    if cfg!(feature = "af_packet") {
+        libs.extend(&["rte_net_af_packet", "rte_bus_vdev"]);
+    }
+    if cfg!(feature = "af_xdp") {
+        libs.extend(&["rte_net_af_xdp", "rte_bus_vdev"]);
+    }
+    if cfg!(feature = "virtio") {
+        libs.extend(&["rte_net_virtio", "rte_bus_pci", "rte_bus_vdev"]);
+    }
+    if cfg!(feature = "ixgbe") {
+        libs.extend(&["rte_net_ixgbe", "rte_bus_pci"]);
+    }
+    if cfg!(feature = "i40e") {
+        libs.extend(&["rte_net_i40e", "rte_bus_pci"]);
+    }
+    if cfg!(feature = "ice") {
+        libs.extend(&["rte_net_ice", "rte_bus_pci"]);
+    }
+    libs.sort_unstable();
+    libs.dedup();
+    libs
+}
+
+/// Drivers that export no symbols for `load_drivers` to reference, so that `--as-needed` drops them from the link. As
+/// cargo does not pass link arguments on to the binaries of dependent crates, `load_drivers` loads them at runtime.
+/// The mlx4 driver is loaded separately by `load_mlx_driver`.
+#[allow(dead_code)]
+const UNREFERENCED_DRIVERS: &[&str] = &[
+    "rte_net_af_packet",
+    "rte_net_af_xdp",
+    "rte_net_ice",
+    "rte_net_null",
+    "rte_net_virtio",
+];
+
+/// Returns the name to load the shared library of `lib` by at runtime. This is the target of the `lib<lib>.so`
+/// symlink, which meson points to the versioned soname, or the unversioned name if no such symlink is found.
+#[cfg(target_os = "linux")]
+fn shared_object_name(lib_dirs: &[::std::path::PathBuf], lib: &str) -> String {
+    let unversioned: String = format!("lib{}.so", lib);
+    lib_dirs
+        .iter()
+        .filter_map(|dir| ::std::fs::read_link(dir.join(&unversioned)).ok())
+        .filter_map(|target| target.file_name().and_then(|name| name.to_str()).map(str::to_owned))
+        .find(|name| name.starts_with(&unversioned))
+        .unwrap_or(unversioned)
+}
+
+/// Whether `lib` is a driver or bus library, which registers itself from a constructor.
+#[allow(dead_code)]
+fn is_driver_library(lib: &str) -> bool {
+    const PREFIXES: &[&str] = &[
+        "rte_net_",
+        "rte_bus_",
+        "rte_common_",
+        "rte_mempool_",
+        "rte_crypto_",
+        "rte_compress_",
+        "rte_event_",
+        "rte_dma_",
+        "rte_baseband_",
+        "rte_raw_",
+        "rte_regex_",
+        "rte_vdpa_",
+    ];
+    PREFIXES.iter().any(|prefix| lib.starts_with(prefix))
+}
+
+/// APIs of a DPDK library that bindings are generated for.
+struct DpdkLibrary {
+    /// Cargo feature that enables the library, or `None` if it is always enabled.
+    feature: Option<&'static str>,
+    /// Allowlist patterns for functions.
+    functions: &'static [&'static str],
+    /// Allowlist patterns for types.
+    types: &'static [&'static str],
+    /// Allowlist patterns for variables, which include constants generated from macros.
+    vars: &'static [&'static str],
+}
+
+/// DPDK libraries that bindings can be generated for. `wrapper.h` includes the headers of the libraries whose
+/// `DPDK_RS_<FEATURE>` macro is defined.
+const DPDK_LIBRARIES: &[DpdkLibrary] = &[
+    DpdkLibrary {
+        feature: None,
+        functions: &[
+            "rte_eal_.*",
+            "rte_strerror",
+            "rte_socket_id",
+            "rte_lcore_.*",
+            "rte_delay_us_block",
+            "rte_get_tsc_hz",
+            "rte_auxiliary_register",
+        ],
+        types: &[],
+        vars: &["RTE_MAX_LCORE", "RTE_MAX_NUMA_NODES"],
+    },
+    DpdkLibrary {
+        feature: Some("mempool"),
+        functions: &["rte_mempool_.*", "rte_pktmbuf_.*", "rte_mbuf_.*"],
+        types: &["rte_mempool", "rte_mbuf", "rte_pktmbuf_pool_private"],
+        vars: &["RTE_MBUF_.*", "RTE_PKTMBUF_.*", "RTE_MEMPOOL_.*"],
+    },
+    DpdkLibrary {
+        feature: Some("ethdev"),
+        functions: &["rte_eth_.*"],
+        types: &[
+            "rte_eth_.*",
+            "rte_ether_.*",
+            "rte_ipv4_hdr",
+            "rte_ipv6_hdr",
+            "rte_tcp_hdr",
+            "rte_udp_hdr",
+        ],
+        vars: &["(RTE_)?ETH_.*", "RTE_ETHER_.*", "RTE_MAX_ETHPORTS"],
+    },
+    DpdkLibrary {
+        feature: Some("ring"),
+        functions: &["rte_ring_.*"],
+        types: &["rte_ring.*"],
+        vars: &["RING_F_.*", "RTE_RING_.*"],
+    },
+    DpdkLibrary {
+        feature: Some("flow"),
+        functions: &["rte_flow_.*"],
+        types: &["rte_flow.*"],
+        vars: &["RTE_FLOW_.*"],
+    },
+    DpdkLibrary {
+        feature: Some("hash"),
+        functions: &["rte_hash_.*"],
+        types: &["rte_hash.*"],
+        vars: &["RTE_HASH_.*"],
+    },
+    DpdkLibrary {
+        feature: Some("timer"),
+        functions: &["rte_timer_.*"],
+        types: &["rte_timer.*"],
+        vars: &["RTE_TIMER_.*"],
+    },
+    DpdkLibrary {
+        feature: Some("cryptodev"),
+        functions: &["rte_cryptodev_.*", "rte_crypto_.*"],
+        types: &["rte_cryptodev.*", "rte_crypto_.*"],
+        vars: &["RTE_CRYPTODEV_.*", "RTE_CRYPTO_.*"],
+    },
+    DpdkLibrary {
+        feature: Some("gso"),
+        functions: &["rte_gso_.*"],
+        types: &["rte_gso_ctx"],
+        vars: &["RTE_GSO_.*"],
+    },
+];
+
+/// Returns the DPDK libraries enabled through cargo features.
+fn enabled_libraries() -> impl Iterator<Item = &'static DpdkLibrary> {
+    DPDK_LIBRARIES.iter().filter(|lib| match lib.feature {
+        Some(feature) => env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some(),
+        None => true,
+    })
+}
+
+/// Defines the macros that make `wrapper.h` include the headers of the enabled libraries.
+fn enable_headers(mut builder: Builder) -> Builder {
+    for lib in enabled_libraries() {
+        if let Some(feature) = lib.feature {
+            builder = builder.clang_arg(format!("-DDPDK_RS_{}", feature.to_uppercase()));
+        }
+    }
+    builder
+}
+
+/// Adds the allowlist of the enabled libraries to `builder`, along with the extra patterns listed in the
+/// comma-separated `DPDK_RS_ALLOWLIST`, which apply to functions, types and variables alike.
+#[allow(dead_code)]
+fn allowlist(mut builder: Builder) -> Result<Builder> {
+    builder = enable_headers(builder).allowlist_recursively(true);
+    for lib in enabled_libraries() {
+        for function in lib.functions {
+            builder = builder.allowlist_function(function);
+        }
+        for ty in lib.types {
+            builder = builder.allowlist_type(ty);
+        }
+        for var in lib.vars {
+            builder = builder.allowlist_var(var);
+        }
+    }
+
+    println!("cargo:rerun-if-env-changed=DPDK_RS_ALLOWLIST");
+    if let Some(extra) = env::var_os("DPDK_RS_ALLOWLIST") {
+        let extra = extra
+            .into_string()
+            .map_err(|extra| ::anyhow::anyhow!("DPDK_RS_ALLOWLIST is not valid unicode: {:?}", extra))?;
+        for pattern in extra.split(',').map(str::trim).filter(|pattern| !pattern.is_empty()) {
+            builder = builder
+                .allowlist_function(pattern)
+                .allowlist_type(pattern)
+                .allowlist_var(pattern);
+        }
+    }
+    Ok(builder)
+}
+
+/// A DPDK `static inline` function, or a macro, exposed to Rust through a generated C wrapper named after it with a
+/// `_` suffix. The Rust function forwarding to the wrapper keeps the original name.
+struct InlineFn {
+    /// Cargo feature of the DPDK library the function belongs to, or `None` if it is always enabled.
+    feature: Option<&'static str>,
+    /// C prototype of the function.
+    prototype: &'static str,
+    /// Body of the wrapper, or `None` to call the function of the same name.
+    body: Option<&'static str>,
+}
+
+const fn wrap(feature: Option<&'static str>, prototype: &'static str) -> InlineFn {
+    InlineFn {
+        feature,
+        prototype,
+        body: None,
+    }
+}
+
+const fn custom(feature: Option<&'static str>, prototype: &'static str, body: &'static str) -> InlineFn {
+    InlineFn {
+        feature,
+        prototype,
+        body: Some(body),
+    }
+}
+
+const EAL: Option<&str> = None;
+const MEMPOOL: Option<&str> = Some("mempool");
+const ETHDEV: Option<&str> = Some("ethdev");
+const RING: Option<&str> = Some("ring");
+
+/// Functions that wrappers are generated for. `inlined.h` includes the headers they need.
+const INLINE_FNS: &[InlineFn] = &[
+    // rte_errno.h
+    custom(EAL, "int rte_errno(void)", "return rte_errno;"),
+    // rte_mempool.h
+    wrap(MEMPOOL, "int rte_mempool_get(struct rte_mempool *mp, void **obj_p)"),
+    wrap(MEMPOOL, "void rte_mempool_put(struct rte_mempool *mp, void *obj)"),
+    wrap(
+        MEMPOOL,
+        "int rte_mempool_get_bulk(struct rte_mempool *mp, void **obj_table, unsigned int n)",
+    ),
+    wrap(
+        MEMPOOL,
+        "void rte_mempool_put_bulk(struct rte_mempool *mp, void * const *obj_table, unsigned int n)",
+    ),
+    wrap(MEMPOOL, "int rte_mempool_empty(const struct rte_mempool *mp)"),
+    wrap(MEMPOOL, "int rte_mempool_full(const struct rte_mempool *mp)"),
+    wrap(MEMPOOL, "struct rte_mempool *rte_mempool_from_obj(void *obj)"),
+    wrap(MEMPOOL, "void *rte_mempool_get_priv(struct rte_mempool *mp)"),
+    // rte_mbuf.h
+    wrap(MEMPOOL, "void rte_mbuf_prefetch_part1(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "void rte_mbuf_prefetch_part2(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "uint16_t rte_pktmbuf_priv_size(struct rte_mempool *mp)"),
+    wrap(MEMPOOL, "uint16_t rte_pktmbuf_data_room_size(struct rte_mempool *mp)"),
+    wrap(MEMPOOL, "rte_iova_t rte_mbuf_data_iova(const struct rte_mbuf *mb)"),
+    wrap(
+        MEMPOOL,
+        "rte_iova_t rte_mbuf_data_iova_default(const struct rte_mbuf *mb)",
+    ),
+    wrap(MEMPOOL, "struct rte_mbuf *rte_mbuf_from_indirect(struct rte_mbuf *mi)"),
+    wrap(MEMPOOL, "void *rte_mbuf_to_priv(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "uint16_t rte_mbuf_refcnt_read(const struct rte_mbuf *m)"),
+    wrap(
+        MEMPOOL,
+        "void rte_mbuf_refcnt_set(struct rte_mbuf *m, uint16_t new_value)",
+    ),
+    wrap(
+        MEMPOOL,
+        "uint16_t rte_mbuf_refcnt_update(struct rte_mbuf *m, int16_t value)",
+    ),
+    wrap(MEMPOOL, "struct rte_mbuf *rte_mbuf_raw_alloc(struct rte_mempool *mp)"),
+    wrap(MEMPOOL, "void rte_mbuf_raw_free(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "void rte_pktmbuf_reset_headroom(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "void rte_pktmbuf_reset(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "struct rte_mbuf *rte_pktmbuf_alloc(struct rte_mempool *mp)"),
+    wrap(
+        MEMPOOL,
+        "int rte_pktmbuf_alloc_bulk(struct rte_mempool *mp, struct rte_mbuf **mbufs, unsigned int count)",
+    ),
+    wrap(
+        MEMPOOL,
+        "void rte_pktmbuf_attach(struct rte_mbuf *mi, struct rte_mbuf *m)",
+    ),
+    wrap(MEMPOOL, "void rte_pktmbuf_detach(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "struct rte_mbuf *rte_pktmbuf_prefree_seg(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "void rte_pktmbuf_free_seg(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "void rte_pktmbuf_free(struct rte_mbuf *m)"),
+    // Returns the new value of the reference counter of the first segment, which DPDK does not.
+    custom(
+        MEMPOOL,
+        "uint16_t rte_pktmbuf_refcnt_update(struct rte_mbuf *m, int16_t value)",
+        "rte_pktmbuf_refcnt_update(m, value);\n    return rte_mbuf_refcnt_read(m);",
+    ),
+    wrap(MEMPOOL, "uint16_t rte_pktmbuf_headroom(const struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "uint16_t rte_pktmbuf_tailroom(const struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "struct rte_mbuf *rte_pktmbuf_lastseg(struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "char *rte_pktmbuf_prepend(struct rte_mbuf *m, uint16_t len)"),
+    wrap(MEMPOOL, "char *rte_pktmbuf_append(struct rte_mbuf *m, uint16_t len)"),
+    wrap(MEMPOOL, "char *rte_pktmbuf_adj(struct rte_mbuf *m, uint16_t len)"),
+    wrap(MEMPOOL, "int rte_pktmbuf_trim(struct rte_mbuf *m, uint16_t len)"),
+    wrap(MEMPOOL, "int rte_pktmbuf_is_contiguous(const struct rte_mbuf *m)"),
+    wrap(
+        MEMPOOL,
+        "const void *rte_pktmbuf_read(const struct rte_mbuf *m, uint32_t off, uint32_t len, void *buf)",
+    ),
+    wrap(
+        MEMPOOL,
+        "int rte_pktmbuf_chain(struct rte_mbuf *head, struct rte_mbuf *tail)",
+    ),
+    wrap(MEMPOOL, "int rte_validate_tx_offload(const struct rte_mbuf *m)"),
+    wrap(MEMPOOL, "int rte_pktmbuf_linearize(struct rte_mbuf *m)"),
+    // Sets the `tx_offload` bitfields, which bindgen exposes under names that vary by release.
+    custom(
+        MEMPOOL,
+        "void rte_mbuf_set_tx_offload(struct rte_mbuf *m, uint16_t l2_len, uint16_t l3_len, uint16_t l4_len, \
+         uint16_t tso_segsz)",
+        "m->l2_len = l2_len;\n    m->l3_len = l3_len;\n    m->l4_len = l4_len;\n    m->tso_segsz = tso_segsz;",
+    ),
+    // `packet_type` is in an anonymous union.
+    custom(
+        MEMPOOL,
+        "uint32_t rte_mbuf_packet_type(const struct rte_mbuf *m)",
+        "return m->packet_type;",
+    ),
+    // rte_ethdev.h
+    wrap(
+        ETHDEV,
+        "uint16_t rte_eth_rx_burst(uint16_t port_id, uint16_t queue_id, struct rte_mbuf **rx_pkts, uint16_t nb_pkts)",
+    ),
+    wrap(
+        ETHDEV,
+        "uint16_t rte_eth_tx_burst(uint16_t port_id, uint16_t queue_id, struct rte_mbuf **tx_pkts, uint16_t nb_pkts)",
+    ),
+    wrap(
+        ETHDEV,
+        "uint16_t rte_eth_tx_prepare(uint16_t port_id, uint16_t queue_id, struct rte_mbuf **tx_pkts, uint16_t nb_pkts)",
+    ),
+    wrap(
+        ETHDEV,
+        "int rte_eth_rx_queue_count(uint16_t port_id, uint16_t queue_id)",
+    ),
+    wrap(
+        ETHDEV,
+        "int rte_eth_rx_descriptor_status(uint16_t port_id, uint16_t queue_id, uint16_t offset)",
+    ),
+    wrap(
+        ETHDEV,
+        "int rte_eth_tx_descriptor_status(uint16_t port_id, uint16_t queue_id, uint16_t offset)",
+    ),
+    wrap(
+        ETHDEV,
+        "uint16_t rte_eth_tx_buffer_flush(uint16_t port_id, uint16_t queue_id, struct rte_eth_dev_tx_buffer *buffer)",
+    ),
+    wrap(
+        ETHDEV,
+        "uint16_t rte_eth_tx_buffer(uint16_t port_id, uint16_t queue_id, struct rte_eth_dev_tx_buffer *buffer, \
+         struct rte_mbuf *tx_pkt)",
+    ),
+    // rte_ip.h
+    wrap(
+        ETHDEV,
+        "uint16_t rte_ipv4_phdr_cksum(const struct rte_ipv4_hdr *ipv4_hdr, uint64_t ol_flags)",
+    ),
+    wrap(
+        ETHDEV,
+        "uint16_t rte_ipv6_phdr_cksum(const struct rte_ipv6_hdr *ipv6_hdr, uint64_t ol_flags)",
+    ),
+    wrap(ETHDEV, "uint16_t rte_ipv4_cksum(const struct rte_ipv4_hdr *ipv4_hdr)"),
+    wrap(
+        ETHDEV,
+        "uint16_t rte_ipv4_udptcp_cksum_mbuf(const struct rte_mbuf *m, const struct rte_ipv4_hdr *ipv4_hdr, \
+         uint16_t l4_off)",
+    ),
+    // Macros that bindgen cannot evaluate.
+    custom(ETHDEV, "uint64_t rte_eth_rss_ip(void)", "return RTE_ETH_RSS_IP;"),
+    custom(
+        ETHDEV,
+        "uint64_t rte_eth_rx_offload_tcp_cksum(void)",
+        "return RTE_ETH_RX_OFFLOAD_TCP_CKSUM;",
+    ),
+    custom(
+        ETHDEV,
+        "uint64_t rte_eth_rx_offload_udp_cksum(void)",
+        "return RTE_ETH_RX_OFFLOAD_UDP_CKSUM;",
+    ),
+    custom(
+        ETHDEV,
+        "uint64_t rte_eth_tx_offload_tcp_cksum(void)",
+        "return RTE_ETH_TX_OFFLOAD_TCP_CKSUM;",
+    ),
+    custom(
+        ETHDEV,
+        "uint64_t rte_eth_tx_offload_udp_cksum(void)",
+        "return RTE_ETH_TX_OFFLOAD_UDP_CKSUM;",
+    ),
+    // rte_ring.h
+    wrap(RING, "unsigned int rte_ring_count(const struct rte_ring *r)"),
+    wrap(RING, "unsigned int rte_ring_free_count(const struct rte_ring *r)"),
+    wrap(RING, "int rte_ring_full(const struct rte_ring *r)"),
+    wrap(RING, "int rte_ring_empty(const struct rte_ring *r)"),
+    wrap(RING, "unsigned int rte_ring_get_size(const struct rte_ring *r)"),
+    wrap(RING, "unsigned int rte_ring_get_capacity(const struct rte_ring *r)"),
+    wrap(RING, "int rte_ring_enqueue(struct rte_ring *r, void *obj)"),
+    wrap(RING, "int rte_ring_mp_enqueue(struct rte_ring *r, void *obj)"),
+    wrap(RING, "int rte_ring_sp_enqueue(struct rte_ring *r, void *obj)"),
+    wrap(RING, "int rte_ring_dequeue(struct rte_ring *r, void **obj_p)"),
+    wrap(RING, "int rte_ring_mc_dequeue(struct rte_ring *r, void **obj_p)"),
+    wrap(RING, "int rte_ring_sc_dequeue(struct rte_ring *r, void **obj_p)"),
+    wrap(
+        RING,
+        "unsigned int rte_ring_enqueue_bulk(struct rte_ring *r, void * const *obj_table, unsigned int n, \
+         unsigned int *free_space)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_mp_enqueue_bulk(struct rte_ring *r, void * const *obj_table, unsigned int n, \
+         unsigned int *free_space)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_sp_enqueue_bulk(struct rte_ring *r, void * const *obj_table, unsigned int n, \
+         unsigned int *free_space)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_enqueue_burst(struct rte_ring *r, void * const *obj_table, unsigned int n, \
+         unsigned int *free_space)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_mp_enqueue_burst(struct rte_ring *r, void * const *obj_table, unsigned int n, \
+         unsigned int *free_space)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_sp_enqueue_burst(struct rte_ring *r, void * const *obj_table, unsigned int n, \
+         unsigned int *free_space)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_dequeue_bulk(struct rte_ring *r, void **obj_table, unsigned int n, \
+         unsigned int *available)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_mc_dequeue_bulk(struct rte_ring *r, void **obj_table, unsigned int n, \
+         unsigned int *available)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_sc_dequeue_bulk(struct rte_ring *r, void **obj_table, unsigned int n, \
+         unsigned int *available)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_dequeue_burst(struct rte_ring *r, void **obj_table, unsigned int n, \
+         unsigned int *available)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_mc_dequeue_burst(struct rte_ring *r, void **obj_table, unsigned int n, \
+         unsigned int *available)",
+    ),
+    wrap(
+        RING,
+        "unsigned int rte_ring_sc_dequeue_burst(struct rte_ring *r, void **obj_table, unsigned int n, \
+         unsigned int *available)",
+    ),
+];
+
+/// Translates a C type, given as tokens with `*` split out, to Rust. `void` alone translates to `None`.
+fn rust_type(tokens: &[&str]) -> Option<String> {
+    let base_end: usize = tokens.iter().position(|token| *token == "*").unwrap_or(tokens.len());
+    let base: Vec<&str> = tokens[..base_end]
+        .iter()
+        .copied()
+        .filter(|token| *token != "const")
+        .collect();
+    // The qualifier of each pointee is known before the pointer to it, so start from the innermost type.
+    let mut const_pointee: bool = tokens[..base_end].contains(&"const");
+    let mut ty: String = match base.join(" ").as_str() {
+        "void" if base_end == tokens.len() => return None,
+        "void" => "::std::os::raw::c_void".to_string(),
+        "char" => "::std::os::raw::c_char".to_string(),
+        "int" => "::std::os::raw::c_int".to_string(),
+        "unsigned" | "unsigned int" => "::std::os::raw::c_uint".to_string(),
+        "size_t" => "usize".to_string(),
+        "uint8_t" | "uint16_t" | "uint32_t" | "uint64_t" => format!("u{}", &base[0][4..base[0].len() - 2]),
+        "int8_t" | "int16_t" | "int32_t" | "int64_t" => format!("i{}", &base[0][3..base[0].len() - 2]),
+        _ if base.len() == 2 && base[0] == "struct" => base[1].to_string(),
+        _ if base.len() == 1 => base[0].to_string(),
+        other => panic!("unsupported C type {:?}", other),
+    };
+    for token in &tokens[base_end..] {
+        match *token {
+            "*" => {
+                ty = format!("*{} {}", if const_pointee { "const" } else { "mut" }, ty);
+                const_pointee = false;
+            },
+            "const" => const_pointee = true,
+            other => panic!("unsupported C type qualifier {:?}", other),
+        }
+    }
+    Some(ty)
+}
+
+/// A parsed C prototype.
+struct Prototype<'a> {
+    name: &'a str,
+    ret: &'a str,
+    /// Parameters as (name, declaration).
+    params: Vec<(&'a str, &'a str)>,
+}
+
+/// Splits a C type into tokens, with each `*` as a token of its own.
+fn type_tokens(ty: &str) -> Vec<&str> {
+    let mut tokens: Vec<&str> = vec![];
+    for word in ty.split_whitespace() {
+        let trimmed: &str = word.trim_start_matches('*');
+        tokens.extend(std::iter::repeat("*").take(word.len() - trimmed.len()));
+        let name: &str = trimmed.trim_end_matches('*');
+        if !name.is_empty() {
+            tokens.push(name);
+        }
+        tokens.extend(std::iter::repeat("*").take(trimmed.len() - name.len()));
+    }
+    tokens
+}
+
+/// Splits a declaration such as `const struct rte_mbuf *m` into its type tokens and name.
+fn split_declaration(declaration: &str) -> (Vec<&str>, &str) {
+    let mut tokens: Vec<&str> = type_tokens(declaration);
+    let name: &str = tokens.pop().expect("empty declaration");
+    (tokens, name)
+}
+
+fn parse_prototype(prototype: &str) -> Prototype<'_> {
+    let (head, params) = prototype
+        .strip_suffix(')')
+        .and_then(|prototype| prototype.split_once('('))
+        .unwrap_or_else(|| panic!("malformed prototype {:?}", prototype));
+    let (ret, name) = head.trim_end().split_at(head.trim_end().rfind([' ', '*']).unwrap() + 1);
+    let params: Vec<(&str, &str)> = match params.trim() {
+        "" | "void" => vec![],
+        params => params
+            .split(',')
+            .map(|param| (split_declaration(param).1, param.trim()))
+            .collect(),
+    };
+    Prototype { name, ret, params }
+}
+
+/// Generates `inlined.c`, holding the C wrappers of the enabled entries of [`INLINE_FNS`], and `inlined.rs`, declaring
+/// them and forwarding to them under the original names, in `out_dir`.
+fn generate_inline_fns(out_dir: &Path) -> Result<()> {
+    println!("cargo:rerun-if-changed=inlined.h");
+    let enabled: Vec<&'static str> = enabled_libraries().filter_map(|lib| lib.feature).collect();
+    let mut c: String = String::from("/* Generated by build.rs from INLINE_FNS. */\n\n#include \"inlined.h\"\n");
+    let mut externs: String = String::new();
+    let mut forwards: String = String::new();
+    for inline_fn in INLINE_FNS {
+        if inline_fn.feature.map_or(false, |feature| !enabled.contains(&feature)) {
+            continue;
+        }
+        let prototype: Prototype = parse_prototype(inline_fn.prototype);
+        let ret: Option<String> = rust_type(&type_tokens(prototype.ret));
+        let arg_names: Vec<&str> = prototype.params.iter().map(|(name, _)| *name).collect();
+
+        let c_params: String = if prototype.params.is_empty() {
+            "void".to_string()
+        } else {
+            prototype
+                .params
+                .iter()
+                .map(|(_, decl)| *decl)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let body: String = match inline_fn.body {
+            Some(body) => body.to_string(),
+            None if ret.is_none() => format!("{}({});", prototype.name, arg_names.join(", ")),
+            None => format!("return {}({});", prototype.name, arg_names.join(", ")),
+        };
+        c.push_str(&format!(
+            "\n{}{}_({}) {{\n    {}\n}}\n",
+            prototype.ret, prototype.name, c_params, body
+        ));
+
+        let rust_params: Vec<String> = prototype
+            .params
+            .iter()
+            .map(|(name, decl)| {
+                let (tokens, _) = split_declaration(decl);
+                format!("{}: {}", name, rust_type(&tokens).expect("void parameter"))
+            })
+            .collect();
+        let rust_ret: String = ret.map(|ret| format!(" -> {}", ret)).unwrap_or_default();
+        externs.push_str(&format!(
+            "    fn {}_({}){};\n",
+            prototype.name,
+            rust_params.join(", "),
+            rust_ret
+        ));
+        forwards.push_str(&format!(
+            "\n#[inline]\npub unsafe fn {}({}){} {{\n    {}_({})\n}}\n",
+            prototype.name,
+            rust_params.join(", "),
+            rust_ret,
+            prototype.name,
+            arg_names.join(", ")
+        ));
+    }
+    let rust: String = format!(
+        "// Generated by build.rs from INLINE_FNS.\n\n#[link(name = \"inlined\")]\nextern \"C\" {{\n{}}}\n{}",
+        externs, forwards
+    );
+    ::std::fs::write(out_dir.join("inlined.c"), c)?;
+    ::std::fs::write(out_dir.join("inlined.rs"), rust)?;
+    Ok(())
+}
+
+/// DPDK long-term support releases that the crate tells apart, as (year, month).
+const DPDK_LTS_RELEASES: &[(u32, u32)] = &[(20, 11), (21, 11), (22, 11), (23, 11), (24, 11)];
+
+/// Whether cargo accepts `cargo:rustc-check-cfg`, which it does from 1.80 on. Older versions warn about it unless
+/// run with `-Zcheck-cfg`.
+fn cargo_supports_check_cfg() -> bool {
+    let cargo: String = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = match ::std::process::Command::new(cargo).arg("--version").output() {
+        Ok(output) => output,
+        Err(_) => return false,
+    };
+    // The output reads as in `cargo 1.80.0 (376290515 2024-07-16)`.
+    let version: String = String::from_utf8_lossy(&output.stdout).into_owned();
+    let mut parts = version
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or("")
+        .split('.')
+        .map(str::parse::<u32>);
+    match (parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor))) => (major, minor) >= (1, 80),
+        _ => false,
+    }
+}
+
+/// Emits a `dpdk_<year>_<month>` cfg for every long-term support release up to `version`, as reported by pkg-config
+/// (e.g. `21.11.2`). Code that depends on a rename introduced by a release checks for that release's cfg.
+fn emit_version_cfgs(version: &str) -> Result<()> {
+    let mut parts = version.trim().split('.').map(str::parse::<u32>);
+    let (year, month) = match (parts.next(), parts.next()) {
+        (Some(Ok(year)), Some(Ok(month))) => (year, month),
+        _ => ::anyhow::bail!("unexpected DPDK version {:?}", version),
+    };
+    let check_cfg: bool = cargo_supports_check_cfg();
+    for &(lts_year, lts_month) in DPDK_LTS_RELEASES {
+        if check_cfg {
+            println!("cargo:rustc-check-cfg=cfg(dpdk_{}_{})", lts_year, lts_month);
+        }
+        if (year, month) >= (lts_year, lts_month) {
+            println!("cargo:rustc-cfg=dpdk_{}_{}", lts_year, lts_month);
+        }
+    }
+    Ok(())
+}
+
+/// Builds the DPDK source tree pointed to by `DPDK_SRC` with meson and ninja, installing it under `out_dir`. Only the
+/// drivers selected through cargo features are built. Returns the directory holding the `libdpdk.pc` of the install.
+#[cfg(all(target_os = "linux", feature = "vendored"))]
+fn build_vendored(out_dir: &Path) -> Result<::std::path::PathBuf> {
+    use ::anyhow::{bail, Context};
+    use ::std::process::Command;
+
+    println!("cargo:rerun-if-env-changed=DPDK_SRC");
+    let src_dir =
+        env::var("DPDK_SRC").context("the vendored feature requires DPDK_SRC to point to a DPDK source tree")?;
+    let src_dir: &Path = Path::new(&src_dir);
+    println!("cargo:rerun-if-changed={}", src_dir.join("VERSION").display());
+    println!("cargo:rerun-if-changed={}", src_dir.join("meson_options.txt").display());
+
+    let build_dir = out_dir.join("dpdk-build");
+    let install_dir = out_dir.join("dpdk");
+
+    // Meson names drivers after their location in the source tree, as in `net/mlx5` for `rte_net_mlx5`. It builds
+    // every driver when the list is empty, so always keep the vdev bus.
+    let mut drivers: Vec<String> = driver_libraries()
+        .iter()
+        .map(|lib| lib.trim_start_matches("rte_").replacen('_', "/", 1))
+        .collect();
+    if drivers.is_empty() {
+        drivers.push("bus/vdev".to_string());
+    }
+
+    let mut meson = Command::new("meson");
+    if build_dir.join("build.ninja").exists() {
+        meson.arg("configure");
+    } else {
+        meson.arg("setup").arg(src_dir);
+    }
+    let status = meson
+        .arg(&build_dir)
+        .arg(format!("--prefix={}", install_dir.display()))
+        .arg("--libdir=lib")
+        .arg("--buildtype=release")
+        .arg(format!("-Denable_drivers={}", drivers.join(",")))
+        .arg("-Dtests=false")
+        .arg("-Denable_docs=false")
+        .arg("-Denable_kmods=false")
+        .arg("-Dexamples=")
+        .status()
+        .context("failed to run meson")?;
+    if !status.success() {
+        bail!("meson failed to configure {}: {}", src_dir.display(), status);
+    }
+
+    let status = Command::new("ninja")
+        .arg("-C")
+        .arg(&build_dir)
+        .arg("install")
+        .status()
+        .context("failed to run ninja")?;
+    if !status.success() {
+        bail!("ninja failed to build {}: {}", src_dir.display(), status);
+    }
+
+    // Let binaries of this crate find the shared libraries without setting `LD_LIBRARY_PATH`.
+    if !cfg!(feature = "static") {
+        println!("cargo:rustc-link-arg=-Wl,-rpath,{}", install_dir.join("lib").display());
+    }
+
+    Ok(install_dir.join("lib").join("pkgconfig"))
+}
+
 #[cfg(target_os = "windows")]
 fn os_build() -> Result<()> {
     use ::std::path::PathBuf;
@@ -13,7 +770,11 @@ fn os_build() -> Result<()> {
     let out_dir_s: String = env::var("OUT_DIR")?;
     let out_dir: &Path = Path::new(&out_dir_s);
 
-    let libdpdk_path: String = env::var("LIBDPDK_PATH")?;
+    println!("cargo:rerun-if-env-changed=LIBDPDK_PATH");
+    let libdpdk_path: String = match env::var("LIBDPDK_PATH") {
+        Ok(path) => path,
+        Err(e) => return use_pregenerated_bindings(e.into()),
+    };
 
     let include_path: String = format!("{}{}", libdpdk_path, "\\include");
     let library_path: String = format!("{}{}", libdpdk_path, "\\lib");
@@ -37,15 +798,24 @@ fn os_build() -> Result<()> {
 
     let cflags: &str = "-mavx";
 
+    // There is no pkg-config on Windows, so read the version from the pkg-config file of the install directly.
+    let pc_path: String = format!("{}{}", libdpdk_path, "\\lib\\pkgconfig\\libdpdk.pc");
+    let pc: String = ::std::fs::read_to_string(&pc_path)?;
+    let version: &str = pc
+        .lines()
+        .find_map(|line| line.strip_prefix("Version:"))
+        .ok_or_else(|| ::anyhow::anyhow!("no version in {}", pc_path))?;
+    emit_version_cfgs(version)?;
+
     // Step 1: Now that we've compiled and installed DPDK, point cargo to the libraries.
     println!("cargo:rustc-link-search={}", library_path);
 
-    for lib in &libraries {
+    for lib in libraries.iter().chain(driver_libraries().iter()) {
         println!("cargo:rustc-link-lib=dylib={}", lib);
     }
 
     // Step 2: Generate bindings for the DPDK headers.
-    let bindings: Bindings = Builder::default()
+    let bindings: Bindings = enable_headers(Builder::default())
         .clang_arg(&format!("-I{}", include_path))
         .blocklist_type("rte_arp_ipv4")
         .blocklist_type("rte_arp_hdr")
@@ -60,14 +830,16 @@ fn os_build() -> Result<()> {
         .generate_comments(false)
         .generate()?;
     let bindings_out: PathBuf = out_dir.join("bindings.rs");
-    bindings.write_to_file(bindings_out)?;
+    bindings.write_to_file(&bindings_out)?;
+    update_pregenerated_bindings(&bindings_out, version)?;
 
     // Step 3: Compile a stub file so Rust can access `inline` functions in the headers
     // that aren't compiled into the libraries.
     let mut builder: Build = cc::Build::new();
     builder.opt_level(3);
     builder.flag("-march=native");
-    builder.file("inlined.c");
+    builder.file(out_dir.join("inlined.c"));
+    builder.include(env::var("CARGO_MANIFEST_DIR")?);
     builder.include(include_path);
     builder.compile("inlined");
 
@@ -82,126 +854,151 @@ fn os_build() -> Result<()> {
     let out_dir = Path::new(&out_dir_s);
 
     println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
+    #[cfg(feature = "vendored")]
+    {
+        // Look up the vendored install first, so that pkg-config below reports it rather than a system-wide DPDK.
+        let pkg_config_dir = build_vendored(out_dir)?;
+        let mut pkg_config_path = pkg_config_dir.into_os_string();
+        if let Some(path) = env::var_os("PKG_CONFIG_PATH") {
+            pkg_config_path.push(":");
+            pkg_config_path.push(path);
+        }
+        env::set_var("PKG_CONFIG_PATH", pkg_config_path);
+    }
+    // Static builds also need the private dependencies of libdpdk.
+    let probe = ::pkg_config::Config::new()
+        .cargo_metadata(false)
+        .statik(cfg!(feature = "static"))
+        .probe("libdpdk");
+    let libdpdk: ::pkg_config::Library = match probe {
+        Ok(libdpdk) => libdpdk,
+        Err(e) => return use_pregenerated_bindings(e.into()),
+    };
+    let version: &str = &libdpdk.version;
+    emit_version_cfgs(version)?;
+
+    // Compiler flags other than include paths and defines, notably `-include rte_config.h` and the instruction set
+    // DPDK was built for. These are passed on as-is to both bindgen and cc.
     let cflags_bytes = Command::new("pkg-config")
-        .args(&["--cflags", "libdpdk"])
+        .args(&["--cflags-only-other", "libdpdk"])
         .output()
         .unwrap_or_else(|e| panic!("Failed pkg-config cflags: {:?}", e))
         .stdout;
     let cflags = String::from_utf8(cflags_bytes).unwrap();
+    let other_cflags: Vec<&str> = cflags
+        .split_whitespace()
+        .filter(|flag| !flag.starts_with("-D"))
+        .collect();
+    let defines: Vec<String> = libdpdk
+        .defines
+        .iter()
+        .map(|(name, value)| match value {
+            Some(value) => format!("-D{}={}", name, value),
+            None => format!("-D{}", name),
+        })
+        .collect();
 
-    let mut header_locations = vec![];
-
-    for flag in cflags.split(' ') {
-        if flag.starts_with("-I") {
-            let header_location = flag[2..].trim();
-            header_locations.push(header_location);
+    let mut lib_names: Vec<&str> = vec![];
+    for lib in &libdpdk.libs {
+        // Static builds name archives explicitly, as in `-l:librte_eal.a`.
+        let name: &str = lib
+            .strip_prefix(":lib")
+            .and_then(|name| name.strip_suffix(".a"))
+            .unwrap_or(lib);
+        if !lib_names.contains(&name) {
+            lib_names.push(name);
         }
     }
 
-    let ldflags_bytes = Command::new("pkg-config")
-        .args(&["--libs", "libdpdk"])
-        .output()
-        .unwrap_or_else(|e| panic!("Failed pkg-config ldflags: {:?}", e))
-        .stdout;
-    let ldflags = String::from_utf8(ldflags_bytes).unwrap();
+    // Link in the selected drivers and their dependencies.
+    for lib in driver_libraries() {
+        if !lib_names.contains(&lib) {
+            lib_names.push(lib);
+        }
+    }
 
-    let mut library_location = None;
-    let mut lib_names = vec![];
+    // Step 1: Now that we've compiled and installed DPDK, point cargo to the libraries.
+    for location in &libdpdk.link_paths {
+        println!("cargo:rustc-link-search=native={}", location.display());
+    }
 
-    for flag in ldflags.split(' ') {
-        if flag.starts_with("-L") {
-            library_location = Some(&flag[2..]);
-        } else if flag.starts_with("-l") {
-            lib_names.push(&flag[2..]);
+    for lib_name in &lib_names {
+        if cfg!(feature = "static") && lib_name.starts_with("rte_") {
+            // Drivers and buses register themselves from constructors that nothing references, so their archives must
+            // be linked in whole. DPDK archives are not bundled into the rlib, so that they keep their relative order
+            // on the final link line.
+            if is_driver_library(lib_name) {
+                println!("cargo:rustc-link-lib=static:+whole-archive,-bundle={}", lib_name);
+            } else {
+                println!("cargo:rustc-link-lib=static:-bundle={}", lib_name);
+            }
+        } else {
+            println!("cargo:rustc-link-lib=dylib={}", lib_name);
         }
     }
 
-    // Link in `librte_net_mlx5` and its dependencies if desired.
-    #[cfg(feature = "mlx5")]
-    {
-        lib_names.extend(&["rte_net_mlx5", "rte_bus_pci", "rte_bus_vdev", "rte_common_mlx5"]);
+    // Tell `load_drivers` which drivers to load at runtime. pkg-config leaves system directories out of the link paths.
+    if !cfg!(feature = "static") {
+        let mut lib_dirs: Vec<::std::path::PathBuf> = libdpdk.link_paths.clone();
+        if let Ok(libdir) = ::pkg_config::get_variable("libdpdk", "libdir") {
+            lib_dirs.push(libdir.into());
+        }
+        let names: Vec<String> = driver_libraries()
+            .into_iter()
+            .filter(|lib| UNREFERENCED_DRIVERS.contains(lib))
+            .map(|lib| shared_object_name(&lib_dirs, lib))
+            .collect();
+        println!("cargo:rustc-env=DPDK_RS_DLOPEN_DRIVERS={}", names.join(":"));
+        if cfg!(feature = "mlx4") {
+            println!(
+                "cargo:rustc-env=DPDK_RS_MLX4_LIBRARY={}",
+                shared_object_name(&lib_dirs, "rte_net_mlx4")
+            );
+        }
     }
 
-    // Step 1: Now that we've compiled and installed DPDK, point cargo to the libraries.
-    if let Some(location) = library_location {
-        println!("cargo:rustc-link-search=native={}", location);
+    // Pass on the remaining linker flags. Those that only affect the libraries that follow them are dropped, as
+    // pkg-config loses their position. Static builds link drivers in whole above; dynamic builds get no replacement,
+    // so say so when the install asks for them. Note that cargo only applies these to the targets of this package.
+    const POSITIONAL_LD_ARGS: &[&str] = &[
+        "--whole-archive",
+        "--no-whole-archive",
+        "--as-needed",
+        "--no-as-needed",
+        "--start-group",
+        "--end-group",
+    ];
+    let mut dropped_ld_args: Vec<&str> = vec![];
+    for ld_arg in &libdpdk.ld_args {
+        let (positional, ld_arg): (Vec<&str>, Vec<&str>) = ld_arg
+            .iter()
+            .map(String::as_str)
+            .partition(|arg| POSITIONAL_LD_ARGS.contains(arg));
+        for arg in positional {
+            if !dropped_ld_args.contains(&arg) {
+                dropped_ld_args.push(arg);
+            }
+        }
+        if !ld_arg.is_empty() {
+            println!("cargo:rustc-link-arg=-Wl,{}", ld_arg.join(","));
+        }
     }
-
-    for lib_name in &lib_names {
-        println!("cargo:rustc-link-lib=dylib={}", lib_name);
+    if !cfg!(feature = "static") && !dropped_ld_args.is_empty() {
+        println!(
+            "cargo:warning=dropped the DPDK linker flags {} as their position cannot be kept; enable the static \
+             feature if this install depends on them",
+            dropped_ld_args.join(" ")
+        );
     }
 
     // Step 2: Generate bindings for the DPDK headers.
     let mut builder: Builder = Builder::default();
-    for header_location in &header_locations {
-        builder = builder.clang_arg(&format!("-I{}", header_location));
+    for header_location in &libdpdk.include_paths {
+        builder = builder.clang_arg(&format!("-I{}", header_location.display()));
     }
+    builder = builder.clang_args(&defines).clang_args(&other_cflags);
+    builder = allowlist(builder)?;
     let bindings: Bindings = builder
-        .allowlist_recursively(true)
-        .allowlist_type("rte_mbuf")
-        .allowlist_type("rte_mempool")
-        .allowlist_function("rte_mempool_obj_iter")
-        .allowlist_function("rte_mempool_mem_iter")
-        .allowlist_function("rte_mempool_free")
-        .allowlist_function("rte_eth_tx_burst")
-        .allowlist_function("rte_eth_rx_burst")
-        .allowlist_function("rte_eal_init")
-        .allowlist_type("rte_eth_txconf")
-        .allowlist_type("rte_eth_rxconf")
-        .allowlist_function("rte_eth_dev_socket_id")
-        .allowlist_function("rte_eth_dev_socket_id")
-        .allowlist_function("rte_eth_rx_queue_setup")
-        .allowlist_function("rte_eth_tx_queue_setup")
-        .allowlist_type("rte_eth_fc_conf")
-        .allowlist_function("rte_eth_dev_start")
-        .allowlist_function("rte_eth_dev_flow_ctrl_get")
-        .allowlist_function("rte_strerror")
-        .allowlist_function("rte_eth_dev_count_avail")
-        .allowlist_function("rte_eth_conf")
-        .allowlist_function("rte_eth_dev_configure")
-        .allowlist_function("rte_eth_dev_count_avail")
-        .allowlist_function("rte_eth_dev_get_mtu")
-        .allowlist_function("rte_eth_dev_set_mtu")
-        .allowlist_function("rte_eth_promiscuous_enable")
-        .allowlist_function("rte_eth_dev_is_valid_port")
-        .allowlist_function("rte_eth_dev_flow_ctrl_set")
-        .allowlist_var("RTE_PKTMBUF_HEADROOM")
-        .allowlist_function("rte_mempool_avail_count")
-        .allowlist_function("rte_mempool_in_use_count")
-        .allowlist_var("RTE_ETHER_MAX_JUMBO_FRAME")
-        .allowlist_function("rte_eth_link_get_nowait")
-        .allowlist_var("RTE_ETH_LINK_UP")
-        .allowlist_var("RTE_ETH_LINK_FULL_DUPLEX")
-        .allowlist_function("rte_delay_us_block")
-        .allowlist_function("rte_socket_id")
-        .allowlist_function("rte_pktmbuf_pool_create")
-        .allowlist_type("rte_pktmbuf_pool_private")
-        .allowlist_function("rte_mempool_create_empty")
-        .allowlist_function("rte_pktmbuf_pool_init")
-        .allowlist_function("rte_mempool_populate_default")
-        .allowlist_function("rte_pktmbuf_init")
-        .allowlist_function("rte_mempool_avail_count")
-        .allowlist_function("rte_mempool_in_use_count")
-        .allowlist_function("rte_pktmbuf_clone")
-        .allowlist_type("rte_ether_addr")
-        .allowlist_var("RTE_MBUF_DEFAULT_BUF_SIZE")
-        .allowlist_var("RTE_ETHER_MAX_JUMBO_FRAME_LEN")
-        .allowlist_var("RTE_ETH_RX_OFFLOAD_TCP_CKSUM")
-        .allowlist_var("RTE_ETH_RX_OFFLOAD_UDP_CKSUM")
-        .allowlist_var("RTE_ETH_TX_OFFLOAD_TCP_CKSUM")
-        .allowlist_var("RTE_ETH_TX_OFFLOAD_UDP_CKSUM")
-        .allowlist_var("RTE_ETH_DEV_NO_OWNER")
-        .allowlist_var("RTE_ETHER_MAX_LEN")
-        .allowlist_var("RTE_ETH_RSS_IP")
-        .allowlist_function("rte_eth_find_next_owned_by")
-        .allowlist_var("RTE_MAX_ETHPORTS")
-        .allowlist_function("rte_eth_dev_info_get")
-        .allowlist_function("rte_eth_macaddr_get")
-        .allowlist_var("RTE_ETH_RX_OFFLOAD_IPV4_CKSUM")
-        .allowlist_var("RTE_ETH_RX_OFFLOAD_UDP_CKSUM")
-        .allowlist_var("RTE_ETH_MQ_RX_RSS")
-        .allowlist_var("RTE_ETH_MQ_TX_NONE")
-        .allowlist_function("rte_auxiliary_register")
         .blocklist_type("rte_arp_ipv4")
         .blocklist_type("rte_arp_hdr")
         .clang_arg("-mavx")
@@ -211,7 +1008,8 @@ fn os_build() -> Result<()> {
         .generate()
         .unwrap_or_else(|e| panic!("Failed to generate bindings: {:?}", e));
     let bindings_out = out_dir.join("bindings.rs");
-    bindings.write_to_file(bindings_out).expect("Failed to write bindings");
+    bindings.write_to_file(&bindings_out).expect("Failed to write bindings");
+    update_pregenerated_bindings(&bindings_out, version)?;
 
     // Step 3: Compile a stub file so Rust can access `inline` functions in the headers
     // that aren't compiled into the libraries.
@@ -219,15 +1017,102 @@ fn os_build() -> Result<()> {
     builder.opt_level(3);
     builder.pic(true);
     builder.flag("-march=native");
-    builder.file("inlined.c");
-    for header_location in &header_locations {
+    builder.file(out_dir.join("inlined.c"));
+    builder.include(env::var("CARGO_MANIFEST_DIR")?);
+    for header_location in &libdpdk.include_paths {
         builder.include(header_location);
     }
+    for flag in defines.iter().map(String::as_str).chain(other_cflags.iter().copied()) {
+        builder.flag(flag);
+    }
     builder.compile("inlined");
     Ok(())
 }
 
+/// Directory holding the pre-generated bindings, relative to the manifest directory.
+const PREGENERATED_BINDINGS_DIR: &str = "bindings";
+
+/// Uses the pre-generated bindings instead of running bindgen, after DPDK could not be found with `error`. This is only
+/// done with the `pregenerated-bindings` feature. The file is picked by `DPDK_RS_BINDINGS_VERSION`, as in `22.11`, and
+/// defaults to the newest release present. Nothing is compiled or linked, so this is only good for `cargo check` and
+/// `cargo doc`; the Rust side of the inline function wrappers is generated as usual.
+fn use_pregenerated_bindings(error: ::anyhow::Error) -> Result<()> {
+    if !cfg!(feature = "pregenerated-bindings") {
+        return Err(error.context("DPDK not found, enable the pregenerated-bindings feature to build without it"));
+    }
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR")?).join(PREGENERATED_BINDINGS_DIR);
+    println!("cargo:rerun-if-changed={}", dir.display());
+    println!("cargo:rerun-if-env-changed=DPDK_RS_BINDINGS_VERSION");
+
+    // Files are named after the release they were generated from, as in `dpdk-22.11.rs`.
+    let mut releases: Vec<((u32, u32), String)> = vec![];
+    for entry in ::std::fs::read_dir(&dir)? {
+        let path = entry?.path();
+        let version: &str = match path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("dpdk-"))
+            .and_then(|name| name.strip_suffix(".rs"))
+        {
+            Some(version) => version,
+            None => continue,
+        };
+        let mut parts = version.split('.').map(str::parse::<u32>);
+        if let (Some(Ok(year)), Some(Ok(month))) = (parts.next(), parts.next()) {
+            releases.push(((year, month), version.to_string()));
+        }
+    }
+    releases.sort();
+    let version: String = match env::var("DPDK_RS_BINDINGS_VERSION") {
+        Ok(version) if releases.iter().any(|(_, release)| *release == version) => version,
+        Ok(version) => {
+            let available: Vec<&str> = releases.iter().map(|(_, release)| release.as_str()).collect();
+            ::anyhow::bail!(
+                "no pre-generated bindings for DPDK {} in {}, available: {}",
+                version,
+                dir.display(),
+                available.join(", ")
+            );
+        },
+        Err(_) => match releases.last() {
+            Some((_, release)) => release.clone(),
+            None => ::anyhow::bail!("no pre-generated bindings in {}", dir.display()),
+        },
+    };
+
+    println!(
+        "cargo:warning=DPDK not found, using the pre-generated bindings of DPDK {}; the crate can be checked but not \
+         linked",
+        version
+    );
+    emit_version_cfgs(&version)?;
+    let out_dir = env::var("OUT_DIR")?;
+    ::std::fs::copy(
+        dir.join(format!("dpdk-{}.rs", version)),
+        Path::new(&out_dir).join("bindings.rs"),
+    )?;
+    Ok(())
+}
+
+/// Copies freshly generated bindings into the pre-generated bindings directory when `DPDK_RS_UPDATE_BINDINGS` is set.
+/// The file is named after the DPDK release in `version`.
+fn update_pregenerated_bindings(bindings_out: &Path, version: &str) -> Result<()> {
+    println!("cargo:rerun-if-env-changed=DPDK_RS_UPDATE_BINDINGS");
+    if env::var_os("DPDK_RS_UPDATE_BINDINGS").is_none() {
+        return Ok(());
+    }
+    let release: Vec<&str> = version.trim().split('.').take(2).collect();
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR")?).join(PREGENERATED_BINDINGS_DIR);
+    ::std::fs::copy(bindings_out, dir.join(format!("dpdk-{}.rs", release.join("."))))?;
+    Ok(())
+}
+
 fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    if let Err(e) = generate_inline_fns(Path::new(&out_dir)) {
+        panic!("Failed to generate inline function wrappers: {:?}", e);
+    }
+
     match os_build() {
         Ok(()) => {},
         Err(e) => panic!("Failed to generate bindings: {:?}", e),
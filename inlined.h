@@ -0,0 +1,45 @@
+/*
+ * Copyright (c) Microsoft Corporation.
+ * Licensed under the MIT license.
+ */
+
+/* Headers needed by the wrappers that build.rs generates for INLINE_FNS. */
+
+#include <rte_errno.h>
+#include <rte_mempool.h>
+#include <rte_mbuf.h>
+#include <rte_ethdev.h>
+#include <rte_ether.h>
+#include <rte_ip.h>
+#include <rte_ring.h>
+#include <rte_version.h>
+
+/* DPDK 21.11 added the RTE_ prefix to the ethdev macros. */
+#ifndef RTE_ETH_RSS_IP
+#define RTE_ETH_RSS_IP ETH_RSS_IP
+#define RTE_ETH_RX_OFFLOAD_TCP_CKSUM DEV_RX_OFFLOAD_TCP_CKSUM
+#define RTE_ETH_RX_OFFLOAD_UDP_CKSUM DEV_RX_OFFLOAD_UDP_CKSUM
+#define RTE_ETH_TX_OFFLOAD_TCP_CKSUM DEV_TX_OFFLOAD_TCP_CKSUM
+#define RTE_ETH_TX_OFFLOAD_UDP_CKSUM DEV_TX_OFFLOAD_UDP_CKSUM
+#endif
+
+/* DPDK 21.11 added rte_ipv4_udptcp_cksum_mbuf, which handles headers and payload in different segments. */
+#if RTE_VERSION < RTE_VERSION_NUM(21, 11, 0, 0)
+static inline uint16_t
+rte_ipv4_udptcp_cksum_mbuf(const struct rte_mbuf *m, const struct rte_ipv4_hdr *ipv4_hdr, uint16_t l4_off)
+{
+    uint16_t raw_cksum;
+    uint32_t cksum;
+    uint16_t l4_len = rte_be_to_cpu_16(ipv4_hdr->total_length) - (uint16_t)((ipv4_hdr->version_ihl & 0x0f) * 4);
+
+    if (rte_raw_cksum_mbuf(m, l4_off, l4_len, &raw_cksum))
+        return 0;
+    cksum = raw_cksum + rte_ipv4_phdr_cksum(ipv4_hdr, 0);
+    cksum = ((cksum & 0xffff0000) >> 16) + (cksum & 0xffff);
+    cksum = (~cksum) & 0xffff;
+    /* A computed UDP checksum of 0 is sent as 0xffff, as 0 means that there is none. */
+    if (cksum == 0 && ipv4_hdr->next_proto_id == IPPROTO_UDP)
+        cksum = 0xffff;
+    return (uint16_t)cksum;
+}
+#endif
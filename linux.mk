@@ -29,6 +29,19 @@ endif
 # Set build flags.
 export FLAGS += --profile $(BUILD)
 
-# Set driver version.
-export DRIVER ?= $(shell [ ! -z "`lspci | grep -E "ConnectX-[4,5]"`" ] && echo mlx5 || echo mlx4)
+# Set driver version. Leave empty to build without any driver, e.g. for virtual devices.
+export DRIVER ?= $(shell lspci | grep -qE "ConnectX-[4,5]" && echo mlx5 || (lspci | grep -q "ConnectX-3" && echo mlx4))
+ifneq ($(DRIVER),)
 export FLAGS += --features=$(DRIVER)
+endif
+
+#=======================================================================================================================
+# Targets
+#=======================================================================================================================
+
+# Keep the targets of the Makefile first in line.
+.DEFAULT_GOAL := all
+
+# Runs the EAL smoke test on the null virtual device. Needs the net_null driver in the runtime linker's search path.
+test-eal:
+	$(CARGO) test $(FLAGS) --features=net_null --test eal -- --ignored --nocapture
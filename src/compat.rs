@@ -0,0 +1,51 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+//! Names that changed between DPDK releases, spelled as in the newest release.
+//!
+//! `build.rs` emits a `dpdk_<year>_<month>` cfg for every long-term support release up to the one the crate is built
+//! against, e.g. `dpdk_20_11` and `dpdk_21_11` for DPDK 21.11. Code that uses the items below compiles on all of them.
+
+use crate::rte_eth_rxmode;
+
+// DPDK 21.11 added the `RTE_` prefix to the ethdev enums and macros.
+
+#[cfg(dpdk_21_11)]
+pub use crate::{
+    rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_NONE as RTE_ETH_MQ_RX_NONE,
+    rte_eth_rx_mq_mode_RTE_ETH_MQ_RX_RSS as RTE_ETH_MQ_RX_RSS,
+    rte_eth_tx_mq_mode_RTE_ETH_MQ_TX_NONE as RTE_ETH_MQ_TX_NONE, RTE_ETH_LINK_DOWN, RTE_ETH_LINK_FULL_DUPLEX,
+    RTE_ETH_LINK_HALF_DUPLEX, RTE_ETH_LINK_UP,
+};
+
+#[cfg(not(dpdk_21_11))]
+pub use crate::{
+    rte_eth_rx_mq_mode_ETH_MQ_RX_NONE as RTE_ETH_MQ_RX_NONE, rte_eth_rx_mq_mode_ETH_MQ_RX_RSS as RTE_ETH_MQ_RX_RSS,
+    rte_eth_tx_mq_mode_ETH_MQ_TX_NONE as RTE_ETH_MQ_TX_NONE, ETH_LINK_DOWN as RTE_ETH_LINK_DOWN,
+    ETH_LINK_FULL_DUPLEX as RTE_ETH_LINK_FULL_DUPLEX, ETH_LINK_HALF_DUPLEX as RTE_ETH_LINK_HALF_DUPLEX,
+    ETH_LINK_UP as RTE_ETH_LINK_UP,
+};
+
+/// `DEV_RX_OFFLOAD_JUMBO_FRAME`, which DPDK 21.11 dropped in favor of the MTU.
+#[cfg(not(dpdk_21_11))]
+const DEV_RX_OFFLOAD_JUMBO_FRAME: u64 = 0x0000_0800;
+
+/// Sets the MTU requested from `rte_eth_dev_configure`.
+#[cfg(dpdk_21_11)]
+pub fn set_rx_mtu(rxmode: &mut rte_eth_rxmode, mtu: u16) {
+    rxmode.mtu = mtu as u32;
+}
+
+/// Sets the MTU requested from `rte_eth_dev_configure`.
+///
+/// Releases before 21.11 take the maximum frame length instead, which only applies along with the jumbo frame
+/// offload.
+#[cfg(not(dpdk_21_11))]
+pub fn set_rx_mtu(rxmode: &mut rte_eth_rxmode, mtu: u16) {
+    use crate::{RTE_ETHER_CRC_LEN, RTE_ETHER_HDR_LEN, RTE_ETHER_MTU};
+
+    rxmode.max_rx_pkt_len = mtu as u32 + RTE_ETHER_HDR_LEN + RTE_ETHER_CRC_LEN;
+    if mtu as u32 > RTE_ETHER_MTU {
+        rxmode.offloads |= DEV_RX_OFFLOAD_JUMBO_FRAME;
+    }
+}
@@ -0,0 +1,571 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use crate::{error::DpdkError, rte_eal_cleanup, rte_eal_init};
+use std::{
+    error::Error,
+    ffi::{CString, NulError},
+    fmt,
+    os::raw::{c_char, c_int},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Whether `rte_eal_init` has been called in this process. DPDK cannot be re-initialized once `rte_eal_cleanup` has
+/// run, so this flag is only cleared again if `rte_eal_init` itself fails.
+static EAL_INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// An initialized Environment Abstraction Layer.
+///
+/// The EAL may only be initialized once per process. Dropping this handle calls `rte_eal_cleanup`, after which
+/// DPDK may not be used again. All other DPDK resources should therefore be released before the handle is dropped.
+///
+/// ```no_run
+/// use dpdk_rs::eal::Eal;
+///
+/// let eal = Eal::init(&["app", "--no-huge", "--no-pci", "--vdev=net_null0"]).unwrap();
+/// ```
+pub struct Eal {
+    /// Backing storage for the arguments handed to `rte_eal_init`, which keeps pointers into `argv`.
+    args: Vec<CString>,
+    argv: Vec<*mut c_char>,
+}
+
+impl Eal {
+    /// Initializes the EAL with the given arguments. As with a regular `argv`, the first argument is the program
+    /// name and is not interpreted as an option.
+    ///
+    /// Fails with [`EalError::Nul`] if an argument contains a nul byte, and with [`DpdkError::Already`] wrapped in
+    /// [`EalError::Dpdk`] if the EAL has already been initialized.
+    pub fn init(args: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Eal, EalError> {
+        let args: Vec<CString> = args
+            .into_iter()
+            .enumerate()
+            .map(|(index, arg)| CString::new(arg.as_ref()).map_err(|e| EalError::Nul(index, e)))
+            .collect::<Result<_, _>>()?;
+
+        if EAL_INITIALIZED
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(EalError::Dpdk(DpdkError::Already));
+        }
+
+        let mut argv: Vec<*mut c_char> = args.iter().map(|arg| arg.as_ptr() as *mut c_char).collect();
+        let ret: c_int = unsafe { rte_eal_init(argv.len() as c_int, argv.as_mut_ptr()) };
+        if ret < 0 {
+            let e: DpdkError = DpdkError::last();
+            // Let DPDK decide whether a subsequent attempt is allowed.
+            EAL_INITIALIZED.store(false, Ordering::Release);
+            return Err(EalError::Dpdk(e));
+        }
+
+        Ok(Eal { args, argv })
+    }
+
+    /// Initializes the EAL with the arguments rendered by `args`. Inconsistent arguments are reported as
+    /// [`EalError::Args`] without calling into DPDK.
+    pub fn init_with(args: &EalArgs) -> Result<Eal, EalError> {
+        Eal::init(args.to_argv()?)
+    }
+}
+
+impl Drop for Eal {
+    fn drop(&mut self) {
+        unsafe {
+            rte_eal_cleanup();
+        }
+    }
+}
+
+/// Errors detected while rendering [`EalArgs`], before they are handed to DPDK.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EalArgsError {
+    /// Two options that DPDK refuses to combine were both set.
+    Conflict(&'static str, &'static str),
+    /// An option was given a value that DPDK would reject.
+    InvalidValue(&'static str, String),
+}
+
+impl fmt::Display for EalArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EalArgsError::Conflict(a, b) => write!(f, "options {} and {} cannot be used together", a, b),
+            EalArgsError::InvalidValue(opt, value) => write!(f, "invalid value {:?} for option {}", value, opt),
+        }
+    }
+}
+
+impl Error for EalArgsError {}
+
+/// Errors of [`Eal::init`] and [`Eal::init_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EalError {
+    /// The arguments could not be rendered.
+    Args(EalArgsError),
+    /// The argument at the given index contains a nul byte.
+    Nul(usize, NulError),
+    /// DPDK failed to initialize.
+    Dpdk(DpdkError),
+}
+
+impl fmt::Display for EalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EalError::Args(e) => write!(f, "invalid EAL arguments: {}", e),
+            EalError::Nul(index, _) => write!(f, "EAL argument {} contains a nul byte", index),
+            EalError::Dpdk(e) => write!(f, "failed to initialize the EAL: {}", e),
+        }
+    }
+}
+
+impl Error for EalError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EalError::Args(e) => Some(e),
+            EalError::Nul(_, e) => Some(e),
+            EalError::Dpdk(e) => Some(e),
+        }
+    }
+}
+
+impl From<EalArgsError> for EalError {
+    fn from(e: EalArgsError) -> Self {
+        EalError::Args(e)
+    }
+}
+
+impl From<DpdkError> for EalError {
+    fn from(e: DpdkError) -> Self {
+        EalError::Dpdk(e)
+    }
+}
+
+/// Whether the DPDK core list `list` includes `lcore`, or `None` if the list uses syntax other than comma-separated
+/// lcores and ranges, such as the `@` of lcore to CPU mappings, which is left for DPDK to check.
+fn core_list_contains(list: &str, lcore: u32) -> Option<bool> {
+    for item in list.split(',') {
+        let (first, last): (&str, &str) = match item.split_once('-') {
+            Some((first, last)) => (first, last),
+            None => (item, item),
+        };
+        let first: u32 = first.trim().parse().ok()?;
+        let last: u32 = last.trim().parse().ok()?;
+        if (first.min(last)..=first.max(last)).contains(&lcore) {
+            return Some(true);
+        }
+    }
+    Some(false)
+}
+
+/// IOVA mode requested through `--iova-mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IovaMode {
+    /// Use physical addresses.
+    Pa,
+    /// Use virtual addresses.
+    Va,
+}
+
+/// Log level accepted by `--log-level`, matching `RTE_LOG_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Emergency = 1,
+    Alert = 2,
+    Critical = 3,
+    Error = 4,
+    Warning = 5,
+    Notice = 6,
+    Info = 7,
+    Debug = 8,
+}
+
+/// Builder for the command line arguments accepted by `rte_eal_init`.
+///
+/// ```no_run
+/// use dpdk_rs::eal::{Eal, EalArgs};
+///
+/// let args = EalArgs::new().cores([0, 1]).no_huge().no_pci().vdev("net_null0");
+/// let eal = Eal::init_with(&args).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EalArgs {
+    program: Option<String>,
+    core_list: Option<String>,
+    core_mask: Option<u64>,
+    main_lcore: Option<u32>,
+    memory_channels: Option<u32>,
+    no_huge: bool,
+    no_pci: bool,
+    in_memory: bool,
+    file_prefix: Option<String>,
+    socket_mem: Vec<u32>,
+    allow: Vec<String>,
+    block: Vec<String>,
+    vdevs: Vec<String>,
+    driver_paths: Vec<String>,
+    iova_mode: Option<IovaMode>,
+    log_levels: Vec<String>,
+    extra: Vec<String>,
+}
+
+impl EalArgs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the program name passed as `argv[0]`. Defaults to the name of the current executable.
+    pub fn program(mut self, name: impl Into<String>) -> Self {
+        self.program = Some(name.into());
+        self
+    }
+
+    /// Runs on the given lcores (`-l`).
+    pub fn cores(mut self, cores: impl IntoIterator<Item = u32>) -> Self {
+        let list: Vec<String> = cores.into_iter().map(|core| core.to_string()).collect();
+        self.core_list = Some(list.join(","));
+        self
+    }
+
+    /// Runs on the lcores described by a DPDK core list such as `0-3,8` (`-l`).
+    pub fn core_list(mut self, list: impl Into<String>) -> Self {
+        self.core_list = Some(list.into());
+        self
+    }
+
+    /// Runs on the lcores set in `mask` (`-c`).
+    pub fn core_mask(mut self, mask: u64) -> Self {
+        self.core_mask = Some(mask);
+        self
+    }
+
+    /// Selects the main lcore (`--main-lcore`).
+    pub fn main_lcore(mut self, lcore: u32) -> Self {
+        self.main_lcore = Some(lcore);
+        self
+    }
+
+    /// Sets the number of memory channels (`-n`).
+    pub fn memory_channels(mut self, channels: u32) -> Self {
+        self.memory_channels = Some(channels);
+        self
+    }
+
+    /// Uses anonymous memory instead of hugepages (`--no-huge`).
+    pub fn no_huge(mut self) -> Self {
+        self.no_huge = true;
+        self
+    }
+
+    /// Disables the PCI bus (`--no-pci`).
+    pub fn no_pci(mut self) -> Self {
+        self.no_pci = true;
+        self
+    }
+
+    /// Does not create any shared files (`--in-memory`).
+    pub fn in_memory(mut self) -> Self {
+        self.in_memory = true;
+        self
+    }
+
+    /// Sets the prefix of hugepage and runtime files (`--file-prefix`).
+    pub fn file_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.file_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Preallocates the given amount of memory, in megabytes, on each NUMA socket (`--socket-mem`).
+    pub fn socket_mem(mut self, megabytes: impl IntoIterator<Item = u32>) -> Self {
+        self.socket_mem = megabytes.into_iter().collect();
+        self
+    }
+
+    /// Adds a PCI device to the allow list (`-a`). Device arguments may follow the address, separated by a comma.
+    pub fn allow(mut self, device: impl Into<String>) -> Self {
+        self.allow.push(device.into());
+        self
+    }
+
+    /// Adds a PCI device to the block list (`-b`).
+    pub fn block(mut self, device: impl Into<String>) -> Self {
+        self.block.push(device.into());
+        self
+    }
+
+    /// Adds a virtual device such as `net_null0` or `net_ring0,nodeaction=...` (`--vdev`).
+    pub fn vdev(mut self, spec: impl Into<String>) -> Self {
+        self.vdevs.push(spec.into());
+        self
+    }
+
+    /// Loads a driver shared object, or all of those in a directory, before probing devices (`-d`).
+    pub fn driver_path(mut self, path: impl Into<String>) -> Self {
+        self.driver_paths.push(path.into());
+        self
+    }
+
+    /// Forces the IOVA mode (`--iova-mode`).
+    pub fn iova_mode(mut self, mode: IovaMode) -> Self {
+        self.iova_mode = Some(mode);
+        self
+    }
+
+    /// Sets the global log level (`--log-level`).
+    pub fn log_level(mut self, level: LogLevel) -> Self {
+        self.log_levels.push((level as u32).to_string());
+        self
+    }
+
+    /// Sets the log level of the log types matching `pattern`, such as `pmd.net.mlx5.*` (`--log-level`).
+    pub fn log_level_for(mut self, pattern: impl AsRef<str>, level: LogLevel) -> Self {
+        self.log_levels.push(format!("{}:{}", pattern.as_ref(), level as u32));
+        self
+    }
+
+    /// Appends a raw argument for options that have no dedicated method.
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.extra.push(arg.into());
+        self
+    }
+
+    /// Renders the arguments into the `argv` expected by `rte_eal_init`, including the program name.
+    pub fn to_argv(&self) -> Result<Vec<String>, EalArgsError> {
+        if self.core_list.is_some() && self.core_mask.is_some() {
+            return Err(EalArgsError::Conflict("-l", "-c"));
+        }
+        if !self.allow.is_empty() && !self.block.is_empty() {
+            return Err(EalArgsError::Conflict("-a", "-b"));
+        }
+        if self.no_huge && !self.socket_mem.is_empty() {
+            return Err(EalArgsError::Conflict("--no-huge", "--socket-mem"));
+        }
+        if self.no_pci && !self.allow.is_empty() {
+            return Err(EalArgsError::Conflict("--no-pci", "-a"));
+        }
+        if let Some(list) = &self.core_list {
+            if list.is_empty() {
+                return Err(EalArgsError::InvalidValue("-l", list.clone()));
+            }
+            if let Some(lcore) = self.main_lcore {
+                if core_list_contains(list, lcore) == Some(false) {
+                    return Err(EalArgsError::InvalidValue("--main-lcore", lcore.to_string()));
+                }
+            }
+        }
+        if let Some(mask) = self.core_mask {
+            if mask == 0 {
+                return Err(EalArgsError::InvalidValue("-c", format!("{:#x}", mask)));
+            }
+            if let Some(lcore) = self.main_lcore {
+                if lcore >= u64::BITS || mask & (1 << lcore) == 0 {
+                    return Err(EalArgsError::InvalidValue("--main-lcore", lcore.to_string()));
+                }
+            }
+        }
+        if self.memory_channels == Some(0) {
+            return Err(EalArgsError::InvalidValue("-n", 0.to_string()));
+        }
+
+        let program: String = match &self.program {
+            Some(program) => program.clone(),
+            None => std::env::args().next().unwrap_or_else(|| "dpdk-rs".to_string()),
+        };
+        let mut argv: Vec<String> = vec![program];
+        if let Some(list) = &self.core_list {
+            argv.push("-l".to_string());
+            argv.push(list.clone());
+        }
+        if let Some(mask) = self.core_mask {
+            argv.push("-c".to_string());
+            argv.push(format!("{:#x}", mask));
+        }
+        if let Some(lcore) = self.main_lcore {
+            argv.push(format!("--main-lcore={}", lcore));
+        }
+        if let Some(channels) = self.memory_channels {
+            argv.push("-n".to_string());
+            argv.push(channels.to_string());
+        }
+        if self.no_huge {
+            argv.push("--no-huge".to_string());
+        }
+        if self.no_pci {
+            argv.push("--no-pci".to_string());
+        }
+        if self.in_memory {
+            argv.push("--in-memory".to_string());
+        }
+        if let Some(prefix) = &self.file_prefix {
+            argv.push(format!("--file-prefix={}", prefix));
+        }
+        if !self.socket_mem.is_empty() {
+            let sockets: Vec<String> = self.socket_mem.iter().map(|mb| mb.to_string()).collect();
+            argv.push(format!("--socket-mem={}", sockets.join(",")));
+        }
+        for device in &self.allow {
+            argv.push("-a".to_string());
+            argv.push(device.clone());
+        }
+        for device in &self.block {
+            argv.push("-b".to_string());
+            argv.push(device.clone());
+        }
+        for spec in &self.vdevs {
+            argv.push(format!("--vdev={}", spec));
+        }
+        for path in &self.driver_paths {
+            argv.push("-d".to_string());
+            argv.push(path.clone());
+        }
+        match self.iova_mode {
+            Some(IovaMode::Pa) => argv.push("--iova-mode=pa".to_string()),
+            Some(IovaMode::Va) => argv.push("--iova-mode=va".to_string()),
+            None => {},
+        }
+        for level in &self.log_levels {
+            argv.push(format!("--log-level={}", level));
+        }
+        argv.extend(self.extra.iter().cloned());
+        Ok(argv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(args: EalArgs) -> Result<Vec<String>, EalArgsError> {
+        args.program("app").to_argv()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(argv(EalArgs::new()), Ok(vec!["app".to_string()]));
+    }
+
+    #[test]
+    fn render() {
+        let args = EalArgs::new()
+            .cores([0, 2])
+            .main_lcore(2)
+            .memory_channels(4)
+            .no_huge()
+            .in_memory()
+            .file_prefix("test")
+            .allow("0000:01:00.0,dv_flow_en=0")
+            .vdev("net_null0")
+            .driver_path("/usr/lib/dpdk")
+            .iova_mode(IovaMode::Va)
+            .log_level(LogLevel::Debug)
+            .log_level_for("pmd.net.*", LogLevel::Error)
+            .arg("--telemetry");
+        let expected: Vec<&str> = vec![
+            "app",
+            "-l",
+            "0,2",
+            "--main-lcore=2",
+            "-n",
+            "4",
+            "--no-huge",
+            "--in-memory",
+            "--file-prefix=test",
+            "-a",
+            "0000:01:00.0,dv_flow_en=0",
+            "--vdev=net_null0",
+            "-d",
+            "/usr/lib/dpdk",
+            "--iova-mode=va",
+            "--log-level=8",
+            "--log-level=pmd.net.*:4",
+            "--telemetry",
+        ];
+        assert_eq!(argv(args).unwrap(), expected);
+    }
+
+    #[test]
+    fn render_mask() {
+        let args = EalArgs::new()
+            .core_mask(0x6)
+            .main_lcore(1)
+            .no_pci()
+            .socket_mem([1024, 0])
+            .block("0000:02:00.0");
+        let expected: Vec<&str> = vec![
+            "app",
+            "-c",
+            "0x6",
+            "--main-lcore=1",
+            "--no-pci",
+            "--socket-mem=1024,0",
+            "-b",
+            "0000:02:00.0",
+        ];
+        assert_eq!(argv(args).unwrap(), expected);
+    }
+
+    #[test]
+    fn conflicts() {
+        assert_eq!(
+            argv(EalArgs::new().cores([0]).core_mask(1)),
+            Err(EalArgsError::Conflict("-l", "-c"))
+        );
+        assert_eq!(
+            argv(EalArgs::new().allow("0000:01:00.0").block("0000:02:00.0")),
+            Err(EalArgsError::Conflict("-a", "-b"))
+        );
+        assert_eq!(
+            argv(EalArgs::new().no_huge().socket_mem([1024])),
+            Err(EalArgsError::Conflict("--no-huge", "--socket-mem"))
+        );
+        assert_eq!(
+            argv(EalArgs::new().no_pci().allow("0000:01:00.0")),
+            Err(EalArgsError::Conflict("--no-pci", "-a"))
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert_eq!(
+            argv(EalArgs::new().core_list("")),
+            Err(EalArgsError::InvalidValue("-l", String::new()))
+        );
+        assert_eq!(
+            argv(EalArgs::new().core_mask(0)),
+            Err(EalArgsError::InvalidValue("-c", "0x0".to_string()))
+        );
+        assert_eq!(
+            argv(EalArgs::new().memory_channels(0)),
+            Err(EalArgsError::InvalidValue("-n", "0".to_string()))
+        );
+    }
+
+    #[test]
+    fn main_lcore() {
+        let invalid = |lcore: u32| Err(EalArgsError::InvalidValue("--main-lcore", lcore.to_string()));
+        assert_eq!(argv(EalArgs::new().core_mask(0x6).main_lcore(0)), invalid(0));
+        assert_eq!(argv(EalArgs::new().core_mask(0x6).main_lcore(64)), invalid(64));
+        assert_eq!(argv(EalArgs::new().cores([0, 1]).main_lcore(2)), invalid(2));
+        assert_eq!(argv(EalArgs::new().core_list("0-3,8").main_lcore(5)), invalid(5));
+        assert!(argv(EalArgs::new().core_list("0-3,8").main_lcore(8)).is_ok());
+        assert!(argv(EalArgs::new().core_list("0-3,8").main_lcore(2)).is_ok());
+        // Lcore to CPU mappings are left for DPDK to check.
+        assert!(argv(EalArgs::new().core_list("(0,1)@2").main_lcore(5)).is_ok());
+    }
+
+    #[test]
+    fn core_list() {
+        assert_eq!(core_list_contains("1", 1), Some(true));
+        assert_eq!(core_list_contains("1, 3", 3), Some(true));
+        assert_eq!(core_list_contains("4-2", 3), Some(true));
+        assert_eq!(core_list_contains("0-3", 4), Some(false));
+        assert_eq!(core_list_contains("0@1", 0), None);
+    }
+
+    #[test]
+    fn nul_argument() {
+        match Eal::init(["app", "--no-pci", "--vdev=net_null0\0"]) {
+            Err(EalError::Nul(2, _)) => {},
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use crate::{rte_errno, rte_strerror};
+use std::{error::Error, ffi::CStr, fmt, os::raw::c_int, ptr::NonNull};
+
+/// Error numbers that DPDK functions return or store in `rte_errno`.
+mod errno {
+    use std::os::raw::c_int;
+
+    pub const EPERM: c_int = 1;
+    pub const ENOENT: c_int = 2;
+    pub const EIO: c_int = 5;
+    pub const EAGAIN: c_int = 11;
+    pub const ENOMEM: c_int = 12;
+    pub const EACCES: c_int = 13;
+    pub const EFAULT: c_int = 14;
+    pub const EBUSY: c_int = 16;
+    pub const EEXIST: c_int = 17;
+    pub const ENODEV: c_int = 19;
+    pub const EINVAL: c_int = 22;
+    pub const ENOSPC: c_int = 28;
+    pub const ERANGE: c_int = 34;
+
+    #[cfg(target_os = "linux")]
+    pub const ENAMETOOLONG: c_int = 36;
+    #[cfg(target_os = "linux")]
+    pub const EOVERFLOW: c_int = 75;
+    #[cfg(target_os = "linux")]
+    pub const ENOTSUP: c_int = 95;
+    #[cfg(target_os = "linux")]
+    pub const ENOBUFS: c_int = 105;
+    #[cfg(target_os = "linux")]
+    pub const EALREADY: c_int = 114;
+
+    #[cfg(target_os = "windows")]
+    pub const ENAMETOOLONG: c_int = 38;
+    #[cfg(target_os = "windows")]
+    pub const EALREADY: c_int = 103;
+    #[cfg(target_os = "windows")]
+    pub const ENOBUFS: c_int = 119;
+    #[cfg(target_os = "windows")]
+    pub const ENOTSUP: c_int = 129;
+    #[cfg(target_os = "windows")]
+    pub const EOVERFLOW: c_int = 132;
+
+    // See `rte_errno.h`.
+    pub const E_RTE_SECONDARY: c_int = 2001;
+    pub const E_RTE_NO_CONFIG: c_int = 2002;
+}
+
+/// An error reported by DPDK, either as a negative return value or through `rte_errno`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DpdkError {
+    /// `EPERM`: operation not permitted.
+    Perm,
+    /// `ENOENT`: no such entry, e.g. an exhausted mempool.
+    NoEnt,
+    /// `EIO`: I/O error.
+    Io,
+    /// `EAGAIN`: resource temporarily unavailable.
+    Again,
+    /// `ENOMEM`: not enough memory.
+    NoMem,
+    /// `EACCES`: permission denied.
+    Acces,
+    /// `EFAULT`: bad address.
+    Fault,
+    /// `EBUSY`: device or resource busy.
+    Busy,
+    /// `EEXIST`: the object already exists, e.g. a mempool with the same name.
+    Exist,
+    /// `ENODEV`: no such device, e.g. an invalid port identifier.
+    NoDev,
+    /// `EINVAL`: invalid argument.
+    Inval,
+    /// `ENOSPC`: no space left.
+    NoSpc,
+    /// `ERANGE`: value out of range.
+    Range,
+    /// `ENAMETOOLONG`: name too long.
+    NameTooLong,
+    /// `EOVERFLOW`: value too large.
+    Overflow,
+    /// `ENOTSUP`: operation not supported by the device or driver.
+    NotSup,
+    /// `ENOBUFS`: no buffer space available.
+    NoBufs,
+    /// `EALREADY`: operation already done, e.g. initializing the EAL twice.
+    Already,
+    /// `E_RTE_SECONDARY`: operation not allowed in a secondary process.
+    Secondary,
+    /// `E_RTE_NO_CONFIG`: missing `rte_config` structure.
+    NoConfig,
+    /// Any other error number.
+    Other(c_int),
+}
+
+impl DpdkError {
+    /// Decodes a positive error number.
+    pub fn from_errno(errnum: c_int) -> Self {
+        match errnum {
+            errno::EPERM => DpdkError::Perm,
+            errno::ENOENT => DpdkError::NoEnt,
+            errno::EIO => DpdkError::Io,
+            errno::EAGAIN => DpdkError::Again,
+            errno::ENOMEM => DpdkError::NoMem,
+            errno::EACCES => DpdkError::Acces,
+            errno::EFAULT => DpdkError::Fault,
+            errno::EBUSY => DpdkError::Busy,
+            errno::EEXIST => DpdkError::Exist,
+            errno::ENODEV => DpdkError::NoDev,
+            errno::EINVAL => DpdkError::Inval,
+            errno::ENOSPC => DpdkError::NoSpc,
+            errno::ERANGE => DpdkError::Range,
+            errno::ENAMETOOLONG => DpdkError::NameTooLong,
+            errno::EOVERFLOW => DpdkError::Overflow,
+            errno::ENOTSUP => DpdkError::NotSup,
+            errno::ENOBUFS => DpdkError::NoBufs,
+            errno::EALREADY => DpdkError::Already,
+            errno::E_RTE_SECONDARY => DpdkError::Secondary,
+            errno::E_RTE_NO_CONFIG => DpdkError::NoConfig,
+            errnum => DpdkError::Other(errnum),
+        }
+    }
+
+    /// Decodes the current value of `rte_errno`. This must be called right after the failing function.
+    pub fn last() -> Self {
+        Self::from_errno(unsafe { rte_errno() })
+    }
+
+    /// Positive error number of this error.
+    pub fn errno(&self) -> c_int {
+        match *self {
+            DpdkError::Perm => errno::EPERM,
+            DpdkError::NoEnt => errno::ENOENT,
+            DpdkError::Io => errno::EIO,
+            DpdkError::Again => errno::EAGAIN,
+            DpdkError::NoMem => errno::ENOMEM,
+            DpdkError::Acces => errno::EACCES,
+            DpdkError::Fault => errno::EFAULT,
+            DpdkError::Busy => errno::EBUSY,
+            DpdkError::Exist => errno::EEXIST,
+            DpdkError::NoDev => errno::ENODEV,
+            DpdkError::Inval => errno::EINVAL,
+            DpdkError::NoSpc => errno::ENOSPC,
+            DpdkError::Range => errno::ERANGE,
+            DpdkError::NameTooLong => errno::ENAMETOOLONG,
+            DpdkError::Overflow => errno::EOVERFLOW,
+            DpdkError::NotSup => errno::ENOTSUP,
+            DpdkError::NoBufs => errno::ENOBUFS,
+            DpdkError::Already => errno::EALREADY,
+            DpdkError::Secondary => errno::E_RTE_SECONDARY,
+            DpdkError::NoConfig => errno::E_RTE_NO_CONFIG,
+            DpdkError::Other(errnum) => errnum,
+        }
+    }
+}
+
+impl fmt::Display for DpdkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = unsafe { CStr::from_ptr(rte_strerror(self.errno())) };
+        write!(f, "{} (errno {})", msg.to_string_lossy(), self.errno())
+    }
+}
+
+impl Error for DpdkError {}
+
+/// Turns the return value of a DPDK function that reports errors as negative error numbers into a `Result`.
+pub fn check(ret: c_int) -> Result<c_int, DpdkError> {
+    if ret < 0 {
+        Err(DpdkError::from_errno(-ret))
+    } else {
+        Ok(ret)
+    }
+}
+
+/// Turns the return value of a DPDK function that reports errors by returning null and setting `rte_errno` into a
+/// `Result`.
+pub fn check_ptr<T>(ptr: *mut T) -> Result<NonNull<T>, DpdkError> {
+    NonNull::new(ptr).ok_or_else(DpdkError::last)
+}
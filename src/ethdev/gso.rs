@@ -0,0 +1,147 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use super::{EthPort, TxOffloads};
+use crate::{
+    error::DpdkError,
+    mbuf::{Mbuf, MbufBatch, TxCsum},
+    mempool::PktMbufPool,
+    rte_gso_ctx, rte_gso_segment,
+};
+use std::{mem::MaybeUninit, sync::Arc};
+
+/// Splits TCP packets into segments of a given size, on the device when it has TSO enabled and with
+/// `rte_gso_segment` otherwise, so that the same code sends large packets on any device.
+///
+/// Software segmentation only supports TCP over IPv4. Each segment is made of a header copied to an mbuf of the
+/// direct pool, chained to an mbuf of the indirect pool attached to its share of the payload, so the port must be
+/// configured with [`TxOffloads::MULTI_SEGS`] on devices that advertise it. `rte_gso_segment` does not compute
+/// checksums: segments ask the device for them if the port has IPv4 and TCP checksum offload enabled, and they are
+/// computed in software otherwise.
+#[derive(Debug)]
+pub struct Gso {
+    /// Whether the device segments packets itself.
+    hardware: bool,
+    /// Whether the device computes the checksums of software segments.
+    checksum_offload: bool,
+    direct: Arc<PktMbufPool>,
+    indirect: Arc<PktMbufPool>,
+}
+
+impl Gso {
+    /// Creates a segmenter for packets sent on `port`. Headers of software segments are allocated from `direct`, and
+    /// mbufs pointing to their payload from `indirect`, which needs no data room.
+    pub fn new(port: &EthPort, direct: Arc<PktMbufPool>, indirect: Arc<PktMbufPool>) -> Self {
+        Gso {
+            hardware: port.tso_enabled(),
+            checksum_offload: port
+                .tx_offloads()
+                .contains(TxOffloads::IPV4_CKSUM | TxOffloads::TCP_CKSUM),
+            direct,
+            indirect,
+        }
+    }
+
+    /// Whether packets are segmented by the device.
+    pub fn is_hardware(&self) -> bool {
+        self.hardware
+    }
+
+    /// Splits `pkt` into segments carrying at most `mss` bytes of TCP payload and appends them to `batch`, returning
+    /// how many were added. With TSO, this only marks the packet with [`Mbuf::request_tso`] and appends it.
+    ///
+    /// On failure, the packet is handed back along with the error: [`DpdkError::NoSpc`] if `batch` cannot hold all
+    /// segments, [`DpdkError::NoMem`] if a pool is exhausted, [`DpdkError::NotSup`] if the packet is not IPv4 and
+    /// must be segmented in software, [`DpdkError::Inval`] if `csum` does not describe a TCP packet, and
+    /// [`DpdkError::Busy`] if the data is shared with another mbuf.
+    pub fn segment<const N: usize>(
+        &mut self,
+        mut pkt: Mbuf,
+        csum: TxCsum,
+        mss: u16,
+        batch: &mut MbufBatch<N>,
+    ) -> Result<usize, (DpdkError, Mbuf)> {
+        if self.hardware {
+            if let Err(e) = pkt.request_tso(csum, mss) {
+                return Err((e, pkt));
+            }
+            return match batch.push(pkt) {
+                Ok(()) => Ok(1),
+                Err(pkt) => Err((DpdkError::NoSpc, pkt)),
+            };
+        }
+
+        if !csum.ipv4 {
+            return Err((DpdkError::NotSup, pkt));
+        }
+        let hdr_len: usize = match pkt.set_tx_offload(csum, Some(mss)) {
+            Ok(len) => len,
+            Err(e) => return Err((e, pkt)),
+        };
+        let pkt_len: usize = pkt.pkt_len();
+        if pkt_len <= hdr_len + mss as usize {
+            // The packet is small enough to be sent as is.
+            return self.push_unsegmented(pkt, csum, batch);
+        }
+        let len: usize = batch.len();
+        let room: usize = (N - len).min(u16::MAX as usize);
+        // `mss` is not zero, which `rte_validate_tx_offload` rejects.
+        if (pkt_len - hdr_len + mss as usize - 1) / mss as usize > room {
+            let _ = pkt.set_tx_offload(csum, None);
+            return Err((DpdkError::NoSpc, pkt));
+        }
+
+        let mut ctx: rte_gso_ctx = unsafe { MaybeUninit::zeroed().assume_init() };
+        ctx.direct_pool = self.direct.as_ptr();
+        ctx.indirect_pool = self.indirect.as_ptr();
+        ctx.gso_types = TxOffloads::TCP_TSO.bits() as _;
+        // The size of the output packets, headers included.
+        ctx.gso_size = (hdr_len + mss as usize).min(u16::MAX as usize) as u16;
+
+        let ret = unsafe { rte_gso_segment(pkt.as_mut_ptr(), &ctx, batch.as_mut_ptr().add(len), room as u16) };
+        if ret < 0 {
+            // Sending the packet as is would need TSO.
+            let _ = pkt.set_tx_offload(csum, None);
+            return Err((DpdkError::from_errno(-ret), pkt));
+        }
+        if ret == 0 {
+            return self.push_unsegmented(pkt, csum, batch);
+        }
+
+        // The segments hold references to the payload of the original packet, which can now be released.
+        drop(pkt);
+        unsafe { batch.set_len(len + ret as usize) };
+        for seg in &mut batch[len..] {
+            // Segments inherit the TSO request, whose pseudo-header checksum leaves out the length.
+            self.finish_checksums(seg, csum)
+                .expect("GSO segments hold their headers in their first mbuf");
+        }
+        Ok(ret as usize)
+    }
+
+    /// Withdraws the TSO request of a packet that is sent as is, and either asks the device for its checksums or
+    /// computes them.
+    fn finish_checksums(&self, pkt: &mut Mbuf, csum: TxCsum) -> Result<(), DpdkError> {
+        if self.checksum_offload {
+            pkt.set_tx_offload(csum, None).map(|_| ())
+        } else {
+            pkt.fill_ipv4_checksums(csum)
+        }
+    }
+
+    /// Appends a packet that needs no segmentation, after withdrawing its TSO request.
+    fn push_unsegmented<const N: usize>(
+        &self,
+        mut pkt: Mbuf,
+        csum: TxCsum,
+        batch: &mut MbufBatch<N>,
+    ) -> Result<usize, (DpdkError, Mbuf)> {
+        if let Err(e) = self.finish_checksums(&mut pkt, csum) {
+            return Err((e, pkt));
+        }
+        match batch.push(pkt) {
+            Ok(()) => Ok(1),
+            Err(pkt) => Err((DpdkError::NoSpc, pkt)),
+        }
+    }
+}
@@ -0,0 +1,557 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+#[cfg(feature = "gso")]
+mod gso;
+mod offload;
+mod queue;
+mod rss;
+
+#[cfg(feature = "gso")]
+pub use self::gso::Gso;
+#[cfg(dpdk_23_11)]
+pub use self::rss::HashFunction;
+pub use self::{
+    offload::{RxOffloads, TxOffloads},
+    queue::{RxQueue, TxQueue},
+    rss::{RssConfig, RssHashFields, RssKey},
+};
+
+use crate::{
+    compat::{
+        self, RTE_ETH_LINK_FULL_DUPLEX, RTE_ETH_LINK_UP, RTE_ETH_MQ_RX_NONE, RTE_ETH_MQ_RX_RSS, RTE_ETH_MQ_TX_NONE,
+    },
+    error::{check, DpdkError},
+    mbuf::PacketType,
+    mempool::PktMbufPool,
+    rte_eth_conf, rte_eth_desc_lim, rte_eth_dev_close, rte_eth_dev_configure, rte_eth_dev_get_supported_ptypes,
+    rte_eth_dev_info, rte_eth_dev_info_get, rte_eth_dev_is_valid_port, rte_eth_dev_rss_hash_update,
+    rte_eth_dev_rss_reta_query, rte_eth_dev_rss_reta_update, rte_eth_dev_set_mtu, rte_eth_dev_socket_id,
+    rte_eth_dev_start, rte_eth_dev_stop, rte_eth_link, rte_eth_link_get_nowait, rte_eth_macaddr_get,
+    rte_eth_promiscuous_enable, rte_eth_rss_conf, rte_eth_rss_reta_entry64, rte_eth_rx_queue_setup, rte_eth_rxconf,
+    rte_eth_tx_queue_setup, rte_eth_txconf, rte_ether_addr,
+};
+use std::{
+    mem::MaybeUninit,
+    os::raw::{c_int, c_uint},
+    sync::{atomic::AtomicBool, Arc},
+};
+
+/// Prefetch, host and write-back thresholds of a queue.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Thresholds {
+    pub pthresh: u8,
+    pub hthresh: u8,
+    pub wthresh: u8,
+}
+
+/// Configuration of an Ethernet device and its queues.
+#[derive(Debug, Clone)]
+pub struct PortConfig {
+    pool: Arc<PktMbufPool>,
+    rx_queues: u16,
+    tx_queues: u16,
+    rx_desc: u16,
+    tx_desc: u16,
+    rx_thresh: Thresholds,
+    tx_thresh: Thresholds,
+    rx_free_thresh: u16,
+    tx_free_thresh: u16,
+    rx_offloads: RxOffloads,
+    tx_offloads: TxOffloads,
+    mtu: Option<u16>,
+    /// Whether promiscuous mode was requested, or `None` to enable it where the device supports it.
+    promiscuous: Option<bool>,
+    tso: bool,
+    rss: RssConfig,
+}
+
+/// Transmit offloads enabled by [`PortConfig::tso`].
+const TSO_OFFLOADS: TxOffloads = TxOffloads::from_bits_truncate(
+    TxOffloads::TCP_TSO.bits() | TxOffloads::IPV4_CKSUM.bits() | TxOffloads::TCP_CKSUM.bits(),
+);
+
+impl PortConfig {
+    /// Creates a configuration with a single queue in each direction, filling receive queues from `pool`.
+    pub fn new(pool: Arc<PktMbufPool>) -> Self {
+        PortConfig {
+            pool,
+            rx_queues: 1,
+            tx_queues: 1,
+            rx_desc: 128,
+            tx_desc: 512,
+            rx_thresh: Thresholds::default(),
+            tx_thresh: Thresholds::default(),
+            rx_free_thresh: 32,
+            tx_free_thresh: 32,
+            rx_offloads: RxOffloads::empty(),
+            tx_offloads: TxOffloads::empty(),
+            mtu: None,
+            promiscuous: None,
+            tso: false,
+            rss: RssConfig::default(),
+        }
+    }
+
+    /// Number of receive queues. Incoming traffic is spread across them with RSS; see [`PortConfig::rss`].
+    pub fn rx_queues(mut self, count: u16) -> Self {
+        self.rx_queues = count;
+        self
+    }
+
+    /// Number of transmit queues.
+    pub fn tx_queues(mut self, count: u16) -> Self {
+        self.tx_queues = count;
+        self
+    }
+
+    /// Number of descriptors of each receive queue.
+    pub fn rx_desc(mut self, count: u16) -> Self {
+        self.rx_desc = count;
+        self
+    }
+
+    /// Number of descriptors of each transmit queue.
+    pub fn tx_desc(mut self, count: u16) -> Self {
+        self.tx_desc = count;
+        self
+    }
+
+    pub fn rx_thresh(mut self, thresh: Thresholds) -> Self {
+        self.rx_thresh = thresh;
+        self
+    }
+
+    pub fn tx_thresh(mut self, thresh: Thresholds) -> Self {
+        self.tx_thresh = thresh;
+        self
+    }
+
+    /// Number of used receive descriptors that triggers replenishing them.
+    pub fn rx_free_thresh(mut self, thresh: u16) -> Self {
+        self.rx_free_thresh = thresh;
+        self
+    }
+
+    /// Number of used transmit descriptors that triggers freeing the transmitted buffers.
+    pub fn tx_free_thresh(mut self, thresh: u16) -> Self {
+        self.tx_free_thresh = thresh;
+        self
+    }
+
+    /// Port-level receive offloads. These must be advertised by [`EthPort::rx_offload_capa`].
+    pub fn rx_offloads(mut self, offloads: RxOffloads) -> Self {
+        self.rx_offloads = offloads;
+        self
+    }
+
+    /// Port-level transmit offloads. These must be advertised by [`EthPort::tx_offload_capa`].
+    pub fn tx_offloads(mut self, offloads: TxOffloads) -> Self {
+        self.tx_offloads = offloads;
+        self
+    }
+
+    /// MTU of the device. The driver default is kept if unset.
+    pub fn mtu(mut self, mtu: u16) -> Self {
+        self.mtu = Some(mtu);
+        self
+    }
+
+    /// Whether to enable TCP segmentation offload, along with the checksum offloads it relies on, if the device
+    /// supports them. Disabled by default.
+    ///
+    /// Unlike offloads requested with [`PortConfig::tx_offloads`], this does not fail on devices without TSO; see
+    /// [`EthPort::tso_enabled`].
+    pub fn tso(mut self, enable: bool) -> Self {
+        self.tso = enable;
+        self
+    }
+
+    /// How incoming traffic is spread across receive queues when there are several. Defaults to hashing on IP
+    /// addresses with the driver's key.
+    pub fn rss(mut self, rss: RssConfig) -> Self {
+        self.rss = rss;
+        self
+    }
+
+    /// Whether to enable promiscuous mode once the device is started. By default, it is enabled on devices that
+    /// support it; once requested here, [`EthPort::configure`] fails on those that do not.
+    pub fn promiscuous(mut self, enable: bool) -> Self {
+        self.promiscuous = Some(enable);
+        self
+    }
+
+    /// Checks the configuration against the limits and offloads advertised by the device.
+    fn validate(&self, dev_info: &rte_eth_dev_info) -> Result<(), DpdkError> {
+        fn desc_in_limits(requested: u16, lim: &rte_eth_desc_lim) -> bool {
+            let aligned: bool = lim.nb_align <= 1 || requested % lim.nb_align == 0;
+            requested >= lim.nb_min && requested <= lim.nb_max && aligned
+        }
+
+        if self.rx_queues == 0 || self.rx_queues > dev_info.max_rx_queues {
+            return Err(DpdkError::Inval);
+        }
+        if self.tx_queues == 0 || self.tx_queues > dev_info.max_tx_queues {
+            return Err(DpdkError::Inval);
+        }
+        if !desc_in_limits(self.rx_desc, &dev_info.rx_desc_lim) {
+            return Err(DpdkError::Inval);
+        }
+        if !desc_in_limits(self.tx_desc, &dev_info.tx_desc_lim) {
+            return Err(DpdkError::Inval);
+        }
+        if let Some(mtu) = self.mtu {
+            if mtu < dev_info.min_mtu || mtu > dev_info.max_mtu {
+                return Err(DpdkError::Inval);
+            }
+        }
+        validate_rss(&self.rss, dev_info)?;
+        if !RxOffloads::from_bits_truncate(dev_info.rx_offload_capa).contains(self.rx_offloads) {
+            return Err(DpdkError::NotSup);
+        }
+        if !TxOffloads::from_bits_truncate(dev_info.tx_offload_capa).contains(self.tx_offloads) {
+            return Err(DpdkError::NotSup);
+        }
+        Ok(())
+    }
+}
+
+/// State of the link of an Ethernet device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Link {
+    pub up: bool,
+    pub speed_mbps: u32,
+    pub full_duplex: bool,
+}
+
+/// A configured and started Ethernet device.
+///
+/// Packets are received and sent through [`RxQueue`] and [`TxQueue`] handles, of which at most one exists per queue.
+/// The device is stopped and closed when this is dropped, which the handles prevent while they are alive.
+#[derive(Debug)]
+pub struct EthPort {
+    port_id: u16,
+    pool: Arc<PktMbufPool>,
+    started: bool,
+    /// Transmit offloads enabled on the device.
+    tx_offloads: TxOffloads,
+    /// Whether a handle to each receive queue has been handed out.
+    rx_queues: Vec<AtomicBool>,
+    /// Whether a handle to each transmit queue has been handed out.
+    tx_queues: Vec<AtomicBool>,
+}
+
+impl EthPort {
+    /// Configures the device attached to `port_id`, sets up its queues and starts it.
+    ///
+    /// Fails with [`DpdkError::NoDev`] if no device is attached to the port, with [`DpdkError::Inval`] if the
+    /// configuration exceeds the limits reported by `rte_eth_dev_info_get`, and with [`DpdkError::NotSup`] if it
+    /// requests offloads or an RSS hash function that the device does not support.
+    pub fn configure(port_id: u16, config: PortConfig) -> Result<EthPort, DpdkError> {
+        if unsafe { rte_eth_dev_is_valid_port(port_id) } == 0 {
+            return Err(DpdkError::NoDev);
+        }
+        let dev_info: rte_eth_dev_info = dev_info(port_id)?;
+        config.validate(&dev_info)?;
+
+        let mut port_conf: rte_eth_conf = unsafe { MaybeUninit::zeroed().assume_init() };
+        // Referenced by `port_conf` until the device is configured.
+        let mut rss_key: Option<Vec<u8>> = config.rss.key.bytes(dev_info.hash_key_size);
+        if config.rx_queues > 1 {
+            port_conf.rxmode.mq_mode = RTE_ETH_MQ_RX_RSS;
+            fill_rss_conf(
+                &mut port_conf.rx_adv_conf.rss_conf,
+                &config.rss,
+                &mut rss_key,
+                &dev_info,
+            );
+        } else {
+            port_conf.rxmode.mq_mode = RTE_ETH_MQ_RX_NONE;
+        }
+        port_conf.rxmode.offloads = config.rx_offloads.bits();
+        if let Some(mtu) = config.mtu {
+            compat::set_rx_mtu(&mut port_conf.rxmode, mtu);
+        }
+        let tso: bool = config.tso && TxOffloads::from_bits_truncate(dev_info.tx_offload_capa).contains(TSO_OFFLOADS);
+        let tx_offloads: TxOffloads = if tso {
+            config.tx_offloads | TSO_OFFLOADS
+        } else {
+            config.tx_offloads
+        };
+        port_conf.txmode.mq_mode = RTE_ETH_MQ_TX_NONE;
+        port_conf.txmode.offloads = tx_offloads.bits();
+
+        let mut rx_conf: rte_eth_rxconf = dev_info.default_rxconf;
+        rx_conf.rx_thresh.pthresh = config.rx_thresh.pthresh;
+        rx_conf.rx_thresh.hthresh = config.rx_thresh.hthresh;
+        rx_conf.rx_thresh.wthresh = config.rx_thresh.wthresh;
+        rx_conf.rx_free_thresh = config.rx_free_thresh;
+        rx_conf.offloads = config.rx_offloads.bits();
+
+        let mut tx_conf: rte_eth_txconf = dev_info.default_txconf;
+        tx_conf.tx_thresh.pthresh = config.tx_thresh.pthresh;
+        tx_conf.tx_thresh.hthresh = config.tx_thresh.hthresh;
+        tx_conf.tx_thresh.wthresh = config.tx_thresh.wthresh;
+        tx_conf.tx_free_thresh = config.tx_free_thresh;
+        tx_conf.offloads = tx_offloads.bits();
+
+        check(unsafe { rte_eth_dev_configure(port_id, config.rx_queues, config.tx_queues, &port_conf) })?;
+
+        // From here on, dropping the port closes the device if anything fails.
+        let mut port: EthPort = EthPort {
+            port_id,
+            pool: config.pool,
+            started: false,
+            tx_offloads,
+            rx_queues: (0..config.rx_queues).map(|_| AtomicBool::new(false)).collect(),
+            tx_queues: (0..config.tx_queues).map(|_| AtomicBool::new(false)).collect(),
+        };
+
+        if let Some(mtu) = config.mtu {
+            check(unsafe { rte_eth_dev_set_mtu(port_id, mtu) })?;
+        }
+
+        let socket_id: c_uint = unsafe { rte_eth_dev_socket_id(port_id) } as c_uint;
+        for queue_id in 0..config.rx_queues {
+            check(unsafe {
+                rte_eth_rx_queue_setup(
+                    port_id,
+                    queue_id,
+                    config.rx_desc,
+                    socket_id,
+                    &rx_conf,
+                    port.pool.as_ptr(),
+                )
+            })?;
+        }
+        for queue_id in 0..config.tx_queues {
+            check(unsafe { rte_eth_tx_queue_setup(port_id, queue_id, config.tx_desc, socket_id, &tx_conf) })?;
+        }
+
+        check(unsafe { rte_eth_dev_start(port_id) })?;
+        port.started = true;
+
+        if config.promiscuous != Some(false) {
+            match check(unsafe { rte_eth_promiscuous_enable(port_id) }) {
+                Err(DpdkError::NotSup) if config.promiscuous.is_none() => {},
+                result => {
+                    result?;
+                },
+            }
+        }
+
+        Ok(port)
+    }
+
+    pub fn port_id(&self) -> u16 {
+        self.port_id
+    }
+
+    /// Number of configured receive queues.
+    pub fn nb_rx_queues(&self) -> u16 {
+        self.rx_queues.len() as u16
+    }
+
+    /// Number of configured transmit queues.
+    pub fn nb_tx_queues(&self) -> u16 {
+        self.tx_queues.len() as u16
+    }
+
+    /// Takes the handle to the given receive queue. The handle is returned to the port when dropped.
+    ///
+    /// Fails with [`DpdkError::Inval`] if the queue does not exist and with [`DpdkError::Busy`] if it is already in
+    /// use.
+    pub fn rx_queue(&self, queue_id: u16) -> Result<RxQueue<'_>, DpdkError> {
+        RxQueue::take(self, queue_id)
+    }
+
+    /// Takes the handle to the given transmit queue. The handle is returned to the port when dropped.
+    ///
+    /// Fails with [`DpdkError::Inval`] if the queue does not exist and with [`DpdkError::Busy`] if it is already in
+    /// use.
+    pub fn tx_queue(&self, queue_id: u16) -> Result<TxQueue<'_>, DpdkError> {
+        TxQueue::take(self, queue_id)
+    }
+
+    /// Whether TCP segmentation offload was enabled with [`PortConfig::tso`]. Packets marked with
+    /// [`Mbuf::request_tso`](crate::mbuf::Mbuf::request_tso) must otherwise be segmented in software before they are
+    /// sent.
+    pub fn tso_enabled(&self) -> bool {
+        self.tx_offloads.contains(TxOffloads::TCP_TSO)
+    }
+
+    /// Transmit offloads enabled on the device, including those enabled by [`PortConfig::tso`].
+    pub fn tx_offloads(&self) -> TxOffloads {
+        self.tx_offloads
+    }
+
+    /// Pool that receive queues are filled from.
+    pub fn pool(&self) -> &Arc<PktMbufPool> {
+        &self.pool
+    }
+
+    /// Information and limits advertised by the device.
+    pub fn dev_info(&self) -> Result<rte_eth_dev_info, DpdkError> {
+        dev_info(self.port_id)
+    }
+
+    /// Receive offloads that the device supports.
+    pub fn rx_offload_capa(&self) -> Result<RxOffloads, DpdkError> {
+        Ok(RxOffloads::from_bits_truncate(self.dev_info()?.rx_offload_capa))
+    }
+
+    /// Transmit offloads that the device supports.
+    pub fn tx_offload_capa(&self) -> Result<TxOffloads, DpdkError> {
+        Ok(TxOffloads::from_bits_truncate(self.dev_info()?.tx_offload_capa))
+    }
+
+    /// Packet types that the device can recognize on receive, as reported in [`Mbuf::packet_type`].
+    ///
+    /// [`Mbuf::packet_type`]: crate::mbuf::Mbuf::packet_type
+    pub fn supported_ptypes(&self) -> Result<Vec<PacketType>, DpdkError> {
+        const RTE_PTYPE_ALL_MASK: u32 = 0xffff_ffff;
+        let num = check(unsafe {
+            rte_eth_dev_get_supported_ptypes(self.port_id, RTE_PTYPE_ALL_MASK, std::ptr::null_mut(), 0)
+        })?;
+        let mut ptypes: Vec<u32> = vec![0; num as usize];
+        let num = check(unsafe {
+            rte_eth_dev_get_supported_ptypes(self.port_id, RTE_PTYPE_ALL_MASK, ptypes.as_mut_ptr(), num)
+        })?;
+        ptypes.truncate(num as usize);
+        Ok(ptypes.into_iter().map(PacketType::from_raw).collect())
+    }
+
+    /// Changes the fields, key and function of the RSS hash. Fails with [`DpdkError::Inval`] if a custom key does not
+    /// have the size reported by the device, and with [`DpdkError::NotSup`] if the device does not support the hash
+    /// function.
+    pub fn rss_hash_update(&self, rss: &RssConfig) -> Result<(), DpdkError> {
+        let dev_info: rte_eth_dev_info = self.dev_info()?;
+        validate_rss(rss, &dev_info)?;
+        let mut rss_key: Option<Vec<u8>> = rss.key.bytes(dev_info.hash_key_size);
+        let mut rss_conf: rte_eth_rss_conf = unsafe { MaybeUninit::zeroed().assume_init() };
+        fill_rss_conf(&mut rss_conf, rss, &mut rss_key, &dev_info);
+        check(unsafe { rte_eth_dev_rss_hash_update(self.port_id, &mut rss_conf) })?;
+        Ok(())
+    }
+
+    /// Number of entries of the RSS redirection table, which maps hash values to receive queues.
+    pub fn reta_size(&self) -> Result<u16, DpdkError> {
+        Ok(self.dev_info()?.reta_size)
+    }
+
+    /// Replaces the RSS redirection table. Packets whose hash modulo the table size is `i` are delivered to the
+    /// receive queue `reta[i]`.
+    ///
+    /// Fails with [`DpdkError::Inval`] if `reta` does not have [`EthPort::reta_size`] entries or names a queue that
+    /// does not exist.
+    pub fn rss_reta_update(&self, reta: &[u16]) -> Result<(), DpdkError> {
+        if reta.len() != self.reta_size()? as usize || reta.iter().any(|&queue| queue >= self.nb_rx_queues()) {
+            return Err(DpdkError::Inval);
+        }
+        let mut entries: Vec<rte_eth_rss_reta_entry64> = reta_entries(reta.len());
+        for (i, &queue) in reta.iter().enumerate() {
+            let entry: &mut rte_eth_rss_reta_entry64 = &mut entries[i / RETA_GROUP_SIZE];
+            entry.mask |= 1 << (i % RETA_GROUP_SIZE);
+            entry.reta[i % RETA_GROUP_SIZE] = queue;
+        }
+        check(unsafe { rte_eth_dev_rss_reta_update(self.port_id, entries.as_mut_ptr(), reta.len() as u16) })?;
+        Ok(())
+    }
+
+    /// Current RSS redirection table; see [`EthPort::rss_reta_update`].
+    pub fn rss_reta_query(&self) -> Result<Vec<u16>, DpdkError> {
+        let reta_size: usize = self.reta_size()? as usize;
+        let mut entries: Vec<rte_eth_rss_reta_entry64> = reta_entries(reta_size);
+        for entry in &mut entries {
+            entry.mask = u64::MAX;
+        }
+        check(unsafe { rte_eth_dev_rss_reta_query(self.port_id, entries.as_mut_ptr(), reta_size as u16) })?;
+        Ok((0..reta_size)
+            .map(|i| entries[i / RETA_GROUP_SIZE].reta[i % RETA_GROUP_SIZE])
+            .collect())
+    }
+
+    /// MAC address of the device.
+    pub fn mac_addr(&self) -> Result<[u8; 6], DpdkError> {
+        let mut addr: MaybeUninit<rte_ether_addr> = MaybeUninit::zeroed();
+        check(unsafe { rte_eth_macaddr_get(self.port_id, addr.as_mut_ptr()) })?;
+        Ok(unsafe { addr.assume_init() }.addr_bytes)
+    }
+
+    /// Current state of the link, without waiting for it to settle.
+    pub fn link(&self) -> Result<Link, DpdkError> {
+        let mut link: MaybeUninit<rte_eth_link> = MaybeUninit::zeroed();
+        check(unsafe { rte_eth_link_get_nowait(self.port_id, link.as_mut_ptr()) })?;
+        let link: rte_eth_link = unsafe { link.assume_init() };
+        Ok(Link {
+            up: link.link_status() as u32 == RTE_ETH_LINK_UP,
+            speed_mbps: link.link_speed,
+            full_duplex: link.link_duplex() as u32 == RTE_ETH_LINK_FULL_DUPLEX,
+        })
+    }
+}
+
+impl Drop for EthPort {
+    fn drop(&mut self) {
+        unsafe {
+            if self.started {
+                rte_eth_dev_stop(self.port_id);
+            }
+            rte_eth_dev_close(self.port_id);
+        }
+    }
+}
+
+fn dev_info(port_id: u16) -> Result<rte_eth_dev_info, DpdkError> {
+    let mut dev_info: MaybeUninit<rte_eth_dev_info> = MaybeUninit::zeroed();
+    check(unsafe { rte_eth_dev_info_get(port_id, dev_info.as_mut_ptr()) })?;
+    Ok(unsafe { dev_info.assume_init() })
+}
+
+/// `RTE_ETH_RETA_GROUP_SIZE`: number of redirection table entries in each `rte_eth_rss_reta_entry64`.
+const RETA_GROUP_SIZE: usize = 64;
+
+/// Zeroed redirection table groups covering `reta_size` entries.
+fn reta_entries(reta_size: usize) -> Vec<rte_eth_rss_reta_entry64> {
+    let groups: usize = (reta_size + RETA_GROUP_SIZE - 1) / RETA_GROUP_SIZE;
+    (0..groups)
+        .map(|_| unsafe { MaybeUninit::zeroed().assume_init() })
+        .collect()
+}
+
+/// Checks that a custom RSS key has the size that the device expects, and that the device supports the hash function.
+fn validate_rss(rss: &RssConfig, dev_info: &rte_eth_dev_info) -> Result<(), DpdkError> {
+    #[cfg(dpdk_23_11)]
+    if !rss.hash_function.is_supported(dev_info.rss_algo_capa) {
+        return Err(DpdkError::NotSup);
+    }
+    if let RssKey::Custom(key) = &rss.key {
+        let expected: bool = if dev_info.hash_key_size == 0 {
+            key.len() <= u8::MAX as usize
+        } else {
+            key.len() == dev_info.hash_key_size as usize
+        };
+        if key.is_empty() || !expected {
+            return Err(DpdkError::Inval);
+        }
+    }
+    Ok(())
+}
+
+/// Fills `rss_conf` from `rss`, pointing it to `key`, which must outlive its use.
+fn fill_rss_conf(
+    rss_conf: &mut rte_eth_rss_conf,
+    rss: &RssConfig,
+    key: &mut Option<Vec<u8>>,
+    dev_info: &rte_eth_dev_info,
+) {
+    rss_conf.rss_hf = rss.hash_fields.bits() & dev_info.flow_type_rss_offloads;
+    if let Some(key) = key {
+        rss_conf.rss_key = key.as_mut_ptr();
+        rss_conf.rss_key_len = key.len() as u8;
+    }
+    #[cfg(dpdk_23_11)]
+    {
+        rss_conf.algorithm = rss.hash_function.raw();
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use bitflags::bitflags;
+
+// The values mirror the `RTE_ETH_{RX,TX}_OFFLOAD_*` macros, which bindgen cannot evaluate. They have not changed since
+// they were introduced, but some were added or removed along the way. The cfgs name the first long-term support release
+// that has the change.
+
+bitflags! {
+    /// Receive offloads, as in `RTE_ETH_RX_OFFLOAD_*`.
+    #[derive(Default)]
+    pub struct RxOffloads: u64 {
+        const VLAN_STRIP = 1 << 0;
+        const IPV4_CKSUM = 1 << 1;
+        const UDP_CKSUM = 1 << 2;
+        const TCP_CKSUM = 1 << 3;
+        const TCP_LRO = 1 << 4;
+        const QINQ_STRIP = 1 << 5;
+        const OUTER_IPV4_CKSUM = 1 << 6;
+        const MACSEC_STRIP = 1 << 7;
+        /// Removed in DPDK 22.11.
+        #[cfg(not(dpdk_22_11))]
+        const HEADER_SPLIT = 1 << 8;
+        const VLAN_FILTER = 1 << 9;
+        const VLAN_EXTEND = 1 << 10;
+        /// Removed in DPDK 21.11, where the MTU alone decides whether jumbo frames are received.
+        #[cfg(not(dpdk_21_11))]
+        const JUMBO_FRAME = 1 << 11;
+        const SCATTER = 1 << 13;
+        const TIMESTAMP = 1 << 14;
+        const SECURITY = 1 << 15;
+        const KEEP_CRC = 1 << 16;
+        const SCTP_CKSUM = 1 << 17;
+        const OUTER_UDP_CKSUM = 1 << 18;
+        const RSS_HASH = 1 << 19;
+        const BUFFER_SPLIT = 1 << 20;
+
+        const CHECKSUM = Self::IPV4_CKSUM.bits | Self::UDP_CKSUM.bits | Self::TCP_CKSUM.bits;
+        const VLAN = Self::VLAN_STRIP.bits | Self::VLAN_FILTER.bits | Self::VLAN_EXTEND.bits | Self::QINQ_STRIP.bits;
+    }
+}
+
+bitflags! {
+    /// Transmit offloads, as in `RTE_ETH_TX_OFFLOAD_*`.
+    #[derive(Default)]
+    pub struct TxOffloads: u64 {
+        const VLAN_INSERT = 1 << 0;
+        const IPV4_CKSUM = 1 << 1;
+        const UDP_CKSUM = 1 << 2;
+        const TCP_CKSUM = 1 << 3;
+        const SCTP_CKSUM = 1 << 4;
+        const TCP_TSO = 1 << 5;
+        const UDP_TSO = 1 << 6;
+        const OUTER_IPV4_CKSUM = 1 << 7;
+        const QINQ_INSERT = 1 << 8;
+        const VXLAN_TNL_TSO = 1 << 9;
+        const GRE_TNL_TSO = 1 << 10;
+        const IPIP_TNL_TSO = 1 << 11;
+        const GENEVE_TNL_TSO = 1 << 12;
+        const MACSEC_INSERT = 1 << 13;
+        /// Queues of the device can be used from several threads at once. The queue handles of this crate do not
+        /// rely on it.
+        const MT_LOCKFREE = 1 << 14;
+        const MULTI_SEGS = 1 << 15;
+        /// Transmitted mbufs all come from the same pool and have a reference count of one, so the driver may free
+        /// them in bulk.
+        const MBUF_FAST_FREE = 1 << 16;
+        const SECURITY = 1 << 17;
+        const UDP_TNL_TSO = 1 << 18;
+        const IP_TNL_TSO = 1 << 19;
+        const OUTER_UDP_CKSUM = 1 << 20;
+        /// Added in DPDK 21.08.
+        #[cfg(dpdk_21_11)]
+        const SEND_ON_TIMESTAMP = 1 << 21;
+
+        const CHECKSUM = Self::IPV4_CKSUM.bits | Self::UDP_CKSUM.bits | Self::TCP_CKSUM.bits;
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use super::EthPort;
+use crate::{error::DpdkError, mbuf::MbufBatch, rte_eth_rx_burst, rte_eth_tx_burst, rte_mbuf};
+use std::{
+    cell::Cell,
+    cmp,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Marks the flag of `queue_id` as taken.
+fn take_queue(flags: &[AtomicBool], queue_id: u16) -> Result<(), DpdkError> {
+    let flag: &AtomicBool = flags.get(queue_id as usize).ok_or(DpdkError::Inval)?;
+    if flag.swap(true, Ordering::AcqRel) {
+        return Err(DpdkError::Busy);
+    }
+    Ok(())
+}
+
+/// Exclusive handle to a receive queue.
+///
+/// DPDK queues are not thread-safe, so each one should be polled by a single lcore. The handle may be moved to the
+/// lcore that polls it but cannot be shared, and [`EthPort`] hands out at most one handle per queue.
+pub struct RxQueue<'a> {
+    port: &'a EthPort,
+    queue_id: u16,
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+impl<'a> RxQueue<'a> {
+    pub(super) fn take(port: &'a EthPort, queue_id: u16) -> Result<Self, DpdkError> {
+        take_queue(&port.rx_queues, queue_id)?;
+        Ok(RxQueue {
+            port,
+            queue_id,
+            _not_sync: PhantomData,
+        })
+    }
+
+    pub fn port_id(&self) -> u16 {
+        self.port.port_id
+    }
+
+    pub fn queue_id(&self) -> u16 {
+        self.queue_id
+    }
+
+    /// Receives packets into the free slots of `batch` and returns how many were received.
+    pub fn rx_burst<const N: usize>(&mut self, batch: &mut MbufBatch<N>) -> usize {
+        let len: usize = batch.len();
+        let free: u16 = cmp::min(N - len, u16::MAX as usize) as u16;
+        if free == 0 {
+            return 0;
+        }
+        unsafe {
+            let slots: *mut *mut rte_mbuf = batch.as_mut_ptr().add(len);
+            let received: u16 = rte_eth_rx_burst(self.port.port_id, self.queue_id, slots, free);
+            batch.set_len(len + received as usize);
+            received as usize
+        }
+    }
+}
+
+impl<'a> Drop for RxQueue<'a> {
+    fn drop(&mut self) {
+        self.port.rx_queues[self.queue_id as usize].store(false, Ordering::Release);
+    }
+}
+
+/// Exclusive handle to a transmit queue.
+///
+/// See [`RxQueue`] for the ownership rules.
+pub struct TxQueue<'a> {
+    port: &'a EthPort,
+    queue_id: u16,
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+impl<'a> TxQueue<'a> {
+    pub(super) fn take(port: &'a EthPort, queue_id: u16) -> Result<Self, DpdkError> {
+        take_queue(&port.tx_queues, queue_id)?;
+        Ok(TxQueue {
+            port,
+            queue_id,
+            _not_sync: PhantomData,
+        })
+    }
+
+    pub fn port_id(&self) -> u16 {
+        self.port.port_id
+    }
+
+    pub fn queue_id(&self) -> u16 {
+        self.queue_id
+    }
+
+    /// Hands the packets in `batch` over to the device and returns those that could not be queued, in order, so that
+    /// they can be retried or freed.
+    pub fn tx_burst<const N: usize>(&mut self, mut batch: MbufBatch<N>) -> MbufBatch<N> {
+        let count: u16 = cmp::min(batch.len(), u16::MAX as usize) as u16;
+        if count == 0 {
+            return batch;
+        }
+        unsafe {
+            let sent: u16 = rte_eth_tx_burst(self.port.port_id, self.queue_id, batch.as_mut_ptr(), count);
+            // The driver now owns the packets that were sent and frees them once transmitted.
+            batch.forget_front(sent as usize);
+        }
+        batch
+    }
+}
+
+impl<'a> Drop for TxQueue<'a> {
+    fn drop(&mut self) {
+        self.port.tx_queues[self.queue_id as usize].store(false, Ordering::Release);
+    }
+}
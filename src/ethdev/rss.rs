@@ -0,0 +1,183 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+#[cfg(dpdk_23_11)]
+use crate::{
+    rte_eth_hash_function, rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_DEFAULT,
+    rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_SIMPLE_XOR,
+    rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_SYMMETRIC_TOEPLITZ,
+    rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_SYMMETRIC_TOEPLITZ_SORT,
+    rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_TOEPLITZ,
+};
+use bitflags::bitflags;
+
+/// Length of the Toeplitz key used when the device does not report one.
+const DEFAULT_KEY_LEN: usize = 40;
+
+bitflags! {
+    /// Packet fields that the RSS hash is computed over, as in `RTE_ETH_RSS_*`.
+    ///
+    /// The values mirror the macros, which bindgen cannot evaluate. Flags that the device does not advertise in
+    /// `flow_type_rss_offloads` are ignored.
+    #[derive(Default)]
+    pub struct RssHashFields: u64 {
+        const IPV4 = 1 << 2;
+        const FRAG_IPV4 = 1 << 3;
+        const NONFRAG_IPV4_TCP = 1 << 4;
+        const NONFRAG_IPV4_UDP = 1 << 5;
+        const NONFRAG_IPV4_SCTP = 1 << 6;
+        const NONFRAG_IPV4_OTHER = 1 << 7;
+        const IPV6 = 1 << 8;
+        const FRAG_IPV6 = 1 << 9;
+        const NONFRAG_IPV6_TCP = 1 << 10;
+        const NONFRAG_IPV6_UDP = 1 << 11;
+        const NONFRAG_IPV6_SCTP = 1 << 12;
+        const NONFRAG_IPV6_OTHER = 1 << 13;
+        const L2_PAYLOAD = 1 << 14;
+        const IPV6_EX = 1 << 15;
+        const IPV6_TCP_EX = 1 << 16;
+        const IPV6_UDP_EX = 1 << 17;
+        const PORT = 1 << 18;
+        const VXLAN = 1 << 19;
+        const GENEVE = 1 << 20;
+        const NVGRE = 1 << 21;
+        const GTPU = 1 << 23;
+        const ETH = 1 << 24;
+        const S_VLAN = 1 << 25;
+        const C_VLAN = 1 << 26;
+        const ESP = 1 << 27;
+        const AH = 1 << 28;
+        const L2TPV3 = 1 << 29;
+        const PFCP = 1 << 30;
+        const PPPOE = 1 << 31;
+        const ECPRI = 1 << 32;
+        const MPLS = 1 << 33;
+        const IPV4_CHKSUM = 1 << 34;
+        const L4_CHKSUM = 1 << 35;
+
+        // Restrict the hash to one direction of the fields selected above.
+        const L2_DST_ONLY = 1 << 58;
+        const L2_SRC_ONLY = 1 << 59;
+        const L4_DST_ONLY = 1 << 60;
+        const L4_SRC_ONLY = 1 << 61;
+        const L3_DST_ONLY = 1 << 62;
+        const L3_SRC_ONLY = 1 << 63;
+
+        const IP = Self::IPV4.bits
+            | Self::FRAG_IPV4.bits
+            | Self::NONFRAG_IPV4_OTHER.bits
+            | Self::IPV6.bits
+            | Self::FRAG_IPV6.bits
+            | Self::NONFRAG_IPV6_OTHER.bits
+            | Self::IPV6_EX.bits;
+        const TCP = Self::NONFRAG_IPV4_TCP.bits | Self::NONFRAG_IPV6_TCP.bits | Self::IPV6_TCP_EX.bits;
+        const UDP = Self::NONFRAG_IPV4_UDP.bits | Self::NONFRAG_IPV6_UDP.bits | Self::IPV6_UDP_EX.bits;
+    }
+}
+
+/// Key of the Toeplitz hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RssKey {
+    /// The key chosen by the driver.
+    Default,
+    /// A key of exactly `hash_key_size` bytes, as reported by the device.
+    Custom(Vec<u8>),
+    /// The `0x6d5a` pattern repeated, which hashes both directions of a flow to the same queue.
+    Symmetric,
+}
+
+impl RssKey {
+    /// Bytes of the key for a device with keys of `key_size` bytes, or `None` to keep the driver default.
+    pub(crate) fn bytes(&self, key_size: u8) -> Option<Vec<u8>> {
+        let key_size: usize = if key_size == 0 {
+            DEFAULT_KEY_LEN
+        } else {
+            key_size as usize
+        };
+        match self {
+            RssKey::Default => None,
+            RssKey::Custom(key) => Some(key.clone()),
+            RssKey::Symmetric => Some([0x6d, 0x5a].iter().copied().cycle().take(key_size).collect()),
+        }
+    }
+}
+
+/// Hash function used for RSS, as in `enum rte_eth_hash_function`. Selecting it requires DPDK 23.11.
+#[cfg(dpdk_23_11)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashFunction {
+    /// The function chosen by the driver.
+    Default,
+    Toeplitz,
+    SimpleXor,
+    /// Toeplitz over the fields of both directions combined, so that they hash to the same queue with any key.
+    SymmetricToeplitz,
+    /// Toeplitz over the source and destination fields sorted, with the same effect as `SymmetricToeplitz`.
+    SymmetricToeplitzSort,
+}
+
+#[cfg(dpdk_23_11)]
+impl HashFunction {
+    pub(crate) fn raw(self) -> rte_eth_hash_function {
+        match self {
+            HashFunction::Default => rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_DEFAULT,
+            HashFunction::Toeplitz => rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_TOEPLITZ,
+            HashFunction::SimpleXor => rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_SIMPLE_XOR,
+            HashFunction::SymmetricToeplitz => rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_SYMMETRIC_TOEPLITZ,
+            HashFunction::SymmetricToeplitzSort => rte_eth_hash_function_RTE_ETH_HASH_FUNCTION_SYMMETRIC_TOEPLITZ_SORT,
+        }
+    }
+
+    /// Whether a device advertising `rss_algo_capa` supports this function. The driver default always is.
+    pub(crate) fn is_supported(self, rss_algo_capa: u32) -> bool {
+        // `RTE_ETH_HASH_ALGO_TO_CAPA`: each function has the bit of its value in the capabilities.
+        self == HashFunction::Default || rss_algo_capa & (1 << self.raw()) != 0
+    }
+}
+
+/// Receive side scaling settings: which fields incoming packets are hashed on, and with which key and function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RssConfig {
+    pub(crate) hash_fields: RssHashFields,
+    pub(crate) key: RssKey,
+    #[cfg(dpdk_23_11)]
+    pub(crate) hash_function: HashFunction,
+}
+
+impl RssConfig {
+    /// Creates a configuration hashing on IP addresses with the driver's key and function.
+    pub fn new() -> Self {
+        RssConfig {
+            hash_fields: RssHashFields::IP,
+            key: RssKey::Default,
+            #[cfg(dpdk_23_11)]
+            hash_function: HashFunction::Default,
+        }
+    }
+
+    /// Fields to hash on. Those that the device does not support are left out.
+    pub fn hash_fields(mut self, fields: RssHashFields) -> Self {
+        self.hash_fields = fields;
+        self
+    }
+
+    /// Key of the Toeplitz hash.
+    pub fn key(mut self, key: RssKey) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Hash function. This must be advertised by the device in `rss_algo_capa`, or configuring the port fails with
+    /// [`DpdkError::NotSup`](crate::error::DpdkError::NotSup).
+    #[cfg(dpdk_23_11)]
+    pub fn hash_function(mut self, function: HashFunction) -> Self {
+        self.hash_function = function;
+        self
+    }
+}
+
+impl Default for RssConfig {
+    fn default() -> Self {
+        RssConfig::new()
+    }
+}
@@ -7,136 +7,124 @@
 #![allow(non_snake_case)]
 #![allow(unused)]
 
-use std::os::raw::{c_char, c_int};
+use std::{
+    ffi::{CStr, CString},
+    os::raw::{c_char, c_int, c_void},
+};
 
-#[link(name = "inlined")]
+#[cfg(all(target_os = "linux", not(feature = "static")))]
+#[link(name = "dl")]
 extern "C" {
-    fn rte_pktmbuf_free_(packet: *mut rte_mbuf);
-    fn rte_pktmbuf_alloc_(mp: *mut rte_mempool) -> *mut rte_mbuf;
-    fn rte_eth_tx_burst_(port_id: u16, queue_id: u16, tx_pkts: *mut *mut rte_mbuf, nb_pkts: u16) -> u16;
-    fn rte_eth_rx_burst_(port_id: u16, queue_id: u16, rx_pkts: *mut *mut rte_mbuf, nb_pkts: u16) -> u16;
-    fn rte_mbuf_refcnt_read_(m: *const rte_mbuf) -> u16;
-    fn rte_mbuf_refcnt_update_(m: *mut rte_mbuf, value: i16) -> u16;
-    fn rte_pktmbuf_adj_(packet: *mut rte_mbuf, len: u16) -> *mut c_char;
-    fn rte_pktmbuf_trim_(packet: *mut rte_mbuf, len: u16) -> c_int;
-    fn rte_pktmbuf_headroom_(m: *const rte_mbuf) -> u16;
-    fn rte_pktmbuf_tailroom_(m: *const rte_mbuf) -> u16;
-    fn rte_errno_() -> c_int;
-    fn rte_pktmbuf_chain_(head: *mut rte_mbuf, tail: *mut rte_mbuf) -> c_int;
-    fn rte_eth_rss_ip_() -> ::std::os::raw::c_int;
-    fn rte_eth_tx_offload_tcp_cksum_() -> ::std::os::raw::c_int;
-    fn rte_eth_tx_offload_udp_cksum_() -> ::std::os::raw::c_int;
-    fn rte_eth_rx_offload_tcp_cksum_() -> ::std::os::raw::c_int;
-    fn rte_eth_rx_offload_udp_cksum_() -> ::std::os::raw::c_int;
+    fn dlopen(filename: *const c_char, flag: c_int) -> *mut c_void;
+    fn dlerror() -> *mut c_char;
 }
 
-#[cfg(feature = "mlx5")]
+#[cfg(all(feature = "mlx5", not(feature = "static")))]
 #[link(name = "rte_net_mlx5")]
 extern "C" {
     fn rte_pmd_mlx5_get_dyn_flag_names();
 }
 
-include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
-
-#[inline(never)]
-pub fn load_mlx_driver() {
-    cfg_if::cfg_if! {
-        if #[cfg(feature = "mlx4")] {
-            // Call mlx4 function.
-        } else if #[cfg(feature = "mlx5")] {
-            if std::env::var("DONT_SET_THIS").is_ok() {
-                unsafe {
-                    rte_pmd_mlx5_get_dyn_flag_names();
-                }
-            }
-        } else {
-            compile_error!("Please select a Mellanox version.")
-        }
-    }
-}
-
-#[inline]
-pub unsafe fn rte_pktmbuf_free(packet: *mut rte_mbuf) {
-    rte_pktmbuf_free_(packet)
-}
-
-#[inline]
-pub unsafe fn rte_pktmbuf_alloc(mp: *mut rte_mempool) -> *mut rte_mbuf {
-    rte_pktmbuf_alloc_(mp)
-}
-
-#[inline]
-pub unsafe fn rte_eth_tx_burst(port_id: u16, queue_id: u16, tx_pkts: *mut *mut rte_mbuf, nb_pkts: u16) -> u16 {
-    rte_eth_tx_burst_(port_id, queue_id, tx_pkts, nb_pkts)
-}
-
-#[inline]
-pub unsafe fn rte_eth_rx_burst(port_id: u16, queue_id: u16, rx_pkts: *mut *mut rte_mbuf, nb_pkts: u16) -> u16 {
-    rte_eth_rx_burst_(port_id, queue_id, rx_pkts, nb_pkts)
-}
-
-#[inline]
-pub unsafe fn rte_mbuf_refcnt_read(m: *const rte_mbuf) -> u16 {
-    rte_mbuf_refcnt_read_(m)
-}
-
-#[inline]
-pub unsafe fn rte_mbuf_refcnt_update(m: *mut rte_mbuf, value: i16) -> u16 {
-    rte_mbuf_refcnt_update_(m, value)
-}
-
-#[inline]
-pub unsafe fn rte_pktmbuf_adj(packet: *mut rte_mbuf, len: u16) -> *mut c_char {
-    rte_pktmbuf_adj_(packet, len)
-}
-
-#[inline]
-pub unsafe fn rte_pktmbuf_trim(packet: *mut rte_mbuf, len: u16) -> c_int {
-    rte_pktmbuf_trim_(packet, len)
+#[cfg(all(feature = "net_ring", not(feature = "static")))]
+#[link(name = "rte_net_ring")]
+extern "C" {
+    fn rte_eth_from_ring();
 }
 
-#[inline]
-pub unsafe fn rte_pktmbuf_headroom(m: *const rte_mbuf) -> u16 {
-    rte_pktmbuf_headroom_(m)
+#[cfg(all(feature = "ixgbe", not(feature = "static")))]
+#[link(name = "rte_net_ixgbe")]
+extern "C" {
+    fn rte_pmd_ixgbe_ping_vf();
 }
 
-#[inline]
-pub unsafe fn rte_pktmbuf_tailroom(m: *const rte_mbuf) -> u16 {
-    rte_pktmbuf_tailroom_(m)
+#[cfg(all(feature = "i40e", not(feature = "static")))]
+#[link(name = "rte_net_i40e")]
+extern "C" {
+    fn rte_pmd_i40e_ping_vfs();
 }
 
-#[inline]
-pub unsafe fn rte_errno() -> c_int {
-    rte_errno_()
-}
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
-#[inline]
-pub unsafe fn rte_pktmbuf_chain(head: *mut rte_mbuf, tail: *mut rte_mbuf) -> c_int {
-    rte_pktmbuf_chain_(head, tail)
-}
+// Wrappers for the `static inline` functions of DPDK, generated by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/inlined.rs"));
 
-#[inline]
-pub unsafe fn rte_eth_rss_ip() -> u64 {
-    return rte_eth_rss_ip_() as _;
-}
+#[cfg(feature = "ethdev")]
+pub mod compat;
+pub mod eal;
+pub mod error;
+#[cfg(feature = "ethdev")]
+pub mod ethdev;
+#[cfg(feature = "mempool")]
+pub mod mbuf;
+#[cfg(feature = "mempool")]
+pub mod mempool;
 
-#[inline]
-pub unsafe fn rte_eth_tx_offload_tcp_cksum() -> u64 {
-    return rte_eth_tx_offload_tcp_cksum_() as _;
+#[inline(never)]
+pub fn load_mlx_driver() {
+    // `librte_net_mlx4` exports no symbols, so it cannot be retained by referencing one as is done for mlx5. Load it
+    // by the versioned name `build.rs` found for it instead.
+    #[cfg(all(feature = "mlx4", target_os = "linux", not(feature = "static")))]
+    dlopen_driver(option_env!("DPDK_RS_MLX4_LIBRARY").unwrap_or("librte_net_mlx4.so"));
+    #[cfg(all(feature = "mlx5", not(feature = "static")))]
+    {
+        if std::env::var("DONT_SET_THIS").is_ok() {
+            unsafe {
+                rte_pmd_mlx5_get_dyn_flag_names();
+            }
+        }
+    }
 }
 
-#[inline]
-pub unsafe fn rte_eth_rx_offload_tcp_cksum() -> u64 {
-    return rte_eth_rx_offload_tcp_cksum_() as _;
+/// Loads the shared library of a driver, so that its constructor registers the driver before the EAL probes devices.
+/// Failures are reported on standard error, as the EAL then finds no devices for the driver.
+#[cfg(all(target_os = "linux", not(feature = "static")))]
+fn dlopen_driver(name: &str) {
+    const RTLD_NOW: c_int = 0x2;
+    const RTLD_GLOBAL: c_int = 0x100;
+    let c_name: CString = CString::new(name).expect("library names have no NUL bytes");
+    let handle: *mut c_void = unsafe { dlopen(c_name.as_ptr(), RTLD_NOW | RTLD_GLOBAL) };
+    if handle.is_null() {
+        let error: *mut c_char = unsafe { dlerror() };
+        if error.is_null() {
+            eprintln!("dpdk-rs: failed to load {}", name);
+        } else {
+            eprintln!(
+                "dpdk-rs: failed to load {}: {}",
+                name,
+                unsafe { CStr::from_ptr(error) }.to_string_lossy()
+            );
+        }
+    }
 }
 
-#[inline]
-pub unsafe fn rte_eth_tx_offload_udp_cksum() -> u64 {
-    return rte_eth_tx_offload_udp_cksum_() as _;
+/// Loads the selected drivers that export no symbols, other than mlx4, by the names `build.rs` found for them.
+#[cfg(all(target_os = "linux", not(feature = "static")))]
+fn dlopen_drivers() {
+    let names: &str = option_env!("DPDK_RS_DLOPEN_DRIVERS").unwrap_or("");
+    for name in names.split(':').filter(|name| !name.is_empty()) {
+        dlopen_driver(name);
+    }
 }
 
-#[inline]
-pub unsafe fn rte_eth_rx_offload_udp_cksum() -> u64 {
-    return rte_eth_rx_offload_udp_cksum_() as _;
+/// Keeps the selected poll mode drivers linked in.
+///
+/// Drivers register themselves from library constructors and are otherwise never referenced, so the linker drops them
+/// unless one of their symbols is used. This references one exported symbol of each selected driver that has any, and
+/// loads those without exported symbols (`mlx4`, `net_null`, `af_packet`, `af_xdp`, `virtio` and `ice`) with
+/// `dlopen`. None of this is needed with the `static` feature, which links all drivers in whole.
+#[inline(never)]
+pub fn load_drivers() {
+    load_mlx_driver();
+    #[cfg(all(target_os = "linux", not(feature = "static")))]
+    dlopen_drivers();
+    if std::env::var("DONT_SET_THIS").is_ok() {
+        unsafe {
+            #[cfg(all(feature = "net_ring", not(feature = "static")))]
+            rte_eth_from_ring();
+            #[cfg(all(feature = "ixgbe", not(feature = "static")))]
+            rte_pmd_ixgbe_ping_vf();
+            #[cfg(all(feature = "i40e", not(feature = "static")))]
+            rte_pmd_i40e_ping_vfs();
+        }
+    }
 }
-
@@ -0,0 +1,177 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use super::Mbuf;
+use crate::{rte_mbuf, rte_pktmbuf_free_bulk};
+use std::{
+    fmt,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    os::raw::c_uint,
+    ptr, slice,
+};
+
+/// A fixed-capacity batch of owned packet buffers.
+///
+/// Batches are the unit of work of burst operations: they are filled with `rte_pktmbuf_alloc_bulk` or by a receive
+/// burst, and whatever is left in them is released with a single `rte_pktmbuf_free_bulk` call when they are dropped
+/// or cleared. The capacity defaults to 32, which matches the burst size used by most DPDK applications.
+pub struct MbufBatch<const N: usize = 32> {
+    mbufs: [MaybeUninit<Mbuf>; N],
+    len: usize,
+}
+
+impl<const N: usize> MbufBatch<N> {
+    pub fn new() -> Self {
+        MbufBatch {
+            // An array of `MaybeUninit` does not require initialization.
+            mbufs: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Adds a buffer at the end of the batch, handing it back if the batch is full.
+    pub fn push(&mut self, mbuf: Mbuf) -> Result<(), Mbuf> {
+        if self.is_full() {
+            return Err(mbuf);
+        }
+        self.mbufs[self.len] = MaybeUninit::new(mbuf);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last buffer of the batch.
+    pub fn pop(&mut self) -> Option<Mbuf> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { self.mbufs[self.len].as_ptr().read() })
+    }
+
+    /// Frees all buffers in the batch.
+    pub fn clear(&mut self) {
+        if self.len > 0 {
+            unsafe { rte_pktmbuf_free_bulk(self.as_mut_ptr(), self.len as c_uint) };
+            self.len = 0;
+        }
+    }
+
+    /// Pointer to the first slot of the batch, suitable for APIs that fill or consume `rte_mbuf` arrays.
+    pub fn as_mut_ptr(&mut self) -> *mut *mut rte_mbuf {
+        // `Mbuf` is a transparent wrapper around a pointer to `rte_mbuf`.
+        self.mbufs.as_mut_ptr() as *mut *mut rte_mbuf
+    }
+
+    /// Sets the number of buffers held by the batch.
+    ///
+    /// # Safety
+    ///
+    /// `len` must not exceed the capacity and the first `len` slots must hold buffers owned by the batch. Buffers in
+    /// slots beyond `len` are no longer owned by the batch.
+    pub unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= N);
+        self.len = len;
+    }
+
+    /// Gives up ownership of the first `count` buffers without freeing them and moves the remaining ones to the
+    /// front of the batch.
+    ///
+    /// # Safety
+    ///
+    /// Ownership of the first `count` buffers must have been transferred elsewhere, e.g. to a transmit queue.
+    pub(crate) unsafe fn forget_front(&mut self, count: usize) {
+        debug_assert!(count <= self.len);
+        let remaining: usize = self.len - count;
+        let base: *mut MaybeUninit<Mbuf> = self.mbufs.as_mut_ptr();
+        ptr::copy(base.add(count), base, remaining);
+        self.len = remaining;
+    }
+}
+
+impl<const N: usize> Default for MbufBatch<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for MbufBatch<N> {
+    type Target = [Mbuf];
+
+    fn deref(&self) -> &[Mbuf] {
+        unsafe { slice::from_raw_parts(self.mbufs.as_ptr() as *const Mbuf, self.len) }
+    }
+}
+
+impl<const N: usize> DerefMut for MbufBatch<N> {
+    fn deref_mut(&mut self) -> &mut [Mbuf] {
+        unsafe { slice::from_raw_parts_mut(self.mbufs.as_mut_ptr() as *mut Mbuf, self.len) }
+    }
+}
+
+impl<const N: usize> Drop for MbufBatch<N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<const N: usize> fmt::Debug for MbufBatch<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<const N: usize> IntoIterator for MbufBatch<N> {
+    type IntoIter = IntoIter<N>;
+    type Item = Mbuf;
+
+    fn into_iter(self) -> IntoIter<N> {
+        IntoIter { batch: self, next: 0 }
+    }
+}
+
+/// Iterator that moves buffers out of a [`MbufBatch`] in order.
+pub struct IntoIter<const N: usize> {
+    batch: MbufBatch<N>,
+    next: usize,
+}
+
+impl<const N: usize> Iterator for IntoIter<N> {
+    type Item = Mbuf;
+
+    fn next(&mut self) -> Option<Mbuf> {
+        if self.next == self.batch.len {
+            return None;
+        }
+        let mbuf: Mbuf = unsafe { self.batch.mbufs[self.next].as_ptr().read() };
+        self.next += 1;
+        Some(mbuf)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.batch.len - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const N: usize> Drop for IntoIter<N> {
+    fn drop(&mut self) {
+        // Free the buffers that were not consumed and make sure the batch does not free the others again.
+        let remaining: usize = self.batch.len - self.next;
+        if remaining > 0 {
+            unsafe {
+                let first: *mut *mut rte_mbuf = self.batch.as_mut_ptr().add(self.next);
+                rte_pktmbuf_free_bulk(first, remaining as c_uint);
+            }
+        }
+        self.batch.len = 0;
+    }
+}
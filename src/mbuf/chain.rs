@@ -0,0 +1,273 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use super::{Mbuf, MbufError, RTE_MBUF_F_INDIRECT};
+use crate::{rte_mbuf, rte_mbuf_refcnt_read, rte_pktmbuf_chain, rte_pktmbuf_linearize};
+use std::{cmp, marker::PhantomData, slice};
+
+/// A packet made of one or more chained segments.
+///
+/// Received jumbo frames and packets built by chaining headers in front of a payload span several mbufs linked through
+/// their `next` field. This type walks those segments so that packet data can be accessed at arbitrary offsets
+/// without caring about where one segment ends and the next one begins.
+#[derive(Debug)]
+pub struct MbufChain {
+    head: Mbuf,
+}
+
+/// Data of the segment `m`.
+unsafe fn segment_data<'a>(m: *const rte_mbuf) -> &'a [u8] {
+    let data: *const u8 = ((*m).buf_addr as *const u8).add((*m).data_off as usize);
+    slice::from_raw_parts(data, (*m).data_len as usize)
+}
+
+/// Whether the data of the segment `m` may be visible through another mbuf.
+unsafe fn segment_is_shared(m: *const rte_mbuf) -> bool {
+    rte_mbuf_refcnt_read(m) > 1 || (*m).ol_flags & RTE_MBUF_F_INDIRECT != 0
+}
+
+/// Mutable data of the segment `m`.
+unsafe fn segment_data_mut<'a>(m: *mut rte_mbuf) -> &'a mut [u8] {
+    assert!(!segment_is_shared(m), "cannot mutate shared mbuf data");
+    let data: *mut u8 = ((*m).buf_addr as *mut u8).add((*m).data_off as usize);
+    slice::from_raw_parts_mut(data, (*m).data_len as usize)
+}
+
+impl MbufChain {
+    pub fn new(head: Mbuf) -> Self {
+        MbufChain { head }
+    }
+
+    /// First segment of the packet.
+    pub fn head(&self) -> &Mbuf {
+        &self.head
+    }
+
+    pub fn into_mbuf(self) -> Mbuf {
+        self.head
+    }
+
+    /// Appends the segments of `tail` to the packet. If the packet would exceed the maximum number of segments,
+    /// `tail` is handed back.
+    pub fn push(&mut self, tail: Mbuf) -> Result<(), Mbuf> {
+        let tail: *mut rte_mbuf = tail.into_raw();
+        unsafe {
+            if rte_pktmbuf_chain(self.head.as_mut_ptr(), tail) != 0 {
+                return Err(Mbuf::from_raw(tail));
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of segments, as recorded in the first one.
+    pub fn nb_segs(&self) -> usize {
+        unsafe { (*self.head.as_ptr()).nb_segs as usize }
+    }
+
+    /// Total length of the packet, computed by walking its segments.
+    pub fn len(&self) -> usize {
+        self.segments().map(|segment| segment.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the data of each segment.
+    pub fn segments(&self) -> Segments<'_> {
+        Segments {
+            next: self.head.as_ptr(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Iterates over the mutable data of each segment.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics when it reaches a segment whose data is shared with another mbuf.
+    pub fn segments_mut(&mut self) -> SegmentsMut<'_> {
+        SegmentsMut {
+            next: self.head.as_mut_ptr(),
+            _marker: PhantomData,
+        }
+    }
+
+    fn check_bounds(&self, offset: usize, len: usize) -> Result<(), MbufError> {
+        let pkt_len: usize = self.len();
+        match offset.checked_add(len) {
+            Some(end) if end <= pkt_len => Ok(()),
+            _ => Err(MbufError::OutOfBounds { offset, len, pkt_len }),
+        }
+    }
+
+    /// Copies `dst.len()` bytes starting at `offset` in the packet into `dst`.
+    pub fn copy_to_slice(&self, offset: usize, dst: &mut [u8]) -> Result<(), MbufError> {
+        self.check_bounds(offset, dst.len())?;
+        let mut skip: usize = offset;
+        let mut copied: usize = 0;
+        for segment in self.segments() {
+            if copied == dst.len() {
+                break;
+            }
+            if skip >= segment.len() {
+                skip -= segment.len();
+                continue;
+            }
+            let n: usize = cmp::min(segment.len() - skip, dst.len() - copied);
+            dst[copied..copied + n].copy_from_slice(&segment[skip..skip + n]);
+            copied += n;
+            skip = 0;
+        }
+        Ok(())
+    }
+
+    /// Overwrites `src.len()` bytes starting at `offset` in the packet with `src`. The packet is not grown.
+    ///
+    /// Only the segments holding the range are written, so the others may be shared. Fails with
+    /// [`MbufError::Shared`], leaving the packet unchanged, if one of those that are written is shared.
+    pub fn copy_from_slice(&mut self, offset: usize, src: &[u8]) -> Result<(), MbufError> {
+        self.check_bounds(offset, src.len())?;
+        if src.is_empty() {
+            return Ok(());
+        }
+        // The range is within the packet, so the segments walked below exist.
+        let mut first: *mut rte_mbuf = self.head.as_mut_ptr();
+        let mut skip: usize = offset;
+        unsafe {
+            while skip >= (*first).data_len as usize {
+                skip -= (*first).data_len as usize;
+                first = (*first).next;
+            }
+
+            let mut m: *mut rte_mbuf = first;
+            let mut remaining: usize = skip + src.len();
+            while remaining > 0 {
+                if segment_is_shared(m) {
+                    return Err(MbufError::Shared);
+                }
+                remaining = remaining.saturating_sub((*m).data_len as usize);
+                m = (*m).next;
+            }
+
+            let mut m: *mut rte_mbuf = first;
+            let mut copied: usize = 0;
+            while copied < src.len() {
+                let segment: &mut [u8] = segment_data_mut(m);
+                let n: usize = cmp::min(segment.len() - skip, src.len() - copied);
+                segment[skip..skip + n].copy_from_slice(&src[copied..copied + n]);
+                copied += n;
+                skip = 0;
+                m = (*m).next;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads `len` bytes starting at `offset`, like `rte_pktmbuf_read`. If the bytes are contiguous in a single
+    /// segment they are returned in place; otherwise they are copied into `buf`. Returns `None` if the range lies
+    /// beyond the end of the packet, or if the bytes must be copied and `buf` is shorter than `len`.
+    pub fn read<'a>(&'a self, offset: usize, len: usize, buf: &'a mut [u8]) -> Option<&'a [u8]> {
+        self.check_bounds(offset, len).ok()?;
+        let mut skip: usize = offset;
+        for segment in self.segments() {
+            if skip < segment.len() {
+                if segment.len() - skip >= len {
+                    return Some(&segment[skip..skip + len]);
+                }
+                break;
+            }
+            skip -= segment.len();
+        }
+        let buf: &mut [u8] = buf.get_mut(..len)?;
+        self.copy_to_slice(offset, buf).ok()?;
+        Some(buf)
+    }
+
+    /// Moves all data into the first segment and frees the others, so that [`Mbuf::data`] covers the whole packet.
+    ///
+    /// `rte_pktmbuf_linearize` copies the other segments into the tailroom of the first one, so this fails with
+    /// [`MbufError::NoTailroom`] unless the whole packet fits in the buffer of the first segment. That is rarely the
+    /// case for received jumbo frames, whose segments are all full; use [`MbufChain::to_vec`] to get their data in
+    /// one piece instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data of the first segment is shared with another mbuf.
+    pub fn linearize(&mut self) -> Result<(), MbufError> {
+        assert!(!self.head.is_shared(), "cannot mutate shared mbuf data");
+        let pkt_len: usize = self.head.pkt_len();
+        let available: usize = self.head.data_len() + self.head.tailroom();
+        if pkt_len > available {
+            return Err(MbufError::NoTailroom {
+                requested: pkt_len - self.head.data_len(),
+                available: self.head.tailroom(),
+            });
+        }
+        let ret = unsafe { rte_pktmbuf_linearize(self.head.as_mut_ptr()) };
+        debug_assert_eq!(ret, 0);
+        Ok(())
+    }
+
+    /// Copies the data of the whole packet into a new vector, however many segments it spans.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0; self.len()];
+        self.copy_to_slice(0, &mut data)
+            .expect("the packet holds as many bytes as its length");
+        data
+    }
+}
+
+impl From<Mbuf> for MbufChain {
+    fn from(head: Mbuf) -> Self {
+        MbufChain::new(head)
+    }
+}
+
+impl From<MbufChain> for Mbuf {
+    fn from(chain: MbufChain) -> Self {
+        chain.into_mbuf()
+    }
+}
+
+/// Iterator over the data of the segments of a packet.
+pub struct Segments<'a> {
+    next: *const rte_mbuf,
+    _marker: PhantomData<&'a Mbuf>,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.next.is_null() {
+            return None;
+        }
+        unsafe {
+            let data: &'a [u8] = segment_data(self.next);
+            self.next = (*self.next).next;
+            Some(data)
+        }
+    }
+}
+
+/// Iterator over the mutable data of the segments of a packet.
+pub struct SegmentsMut<'a> {
+    next: *mut rte_mbuf,
+    _marker: PhantomData<&'a mut Mbuf>,
+}
+
+impl<'a> Iterator for SegmentsMut<'a> {
+    type Item = &'a mut [u8];
+
+    fn next(&mut self) -> Option<&'a mut [u8]> {
+        if self.next.is_null() {
+            return None;
+        }
+        unsafe {
+            let data: &'a mut [u8] = segment_data_mut(self.next);
+            self.next = (*self.next).next;
+            Some(data)
+        }
+    }
+}
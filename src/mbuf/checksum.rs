@@ -0,0 +1,253 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use super::{Mbuf, MbufError};
+use crate::{
+    error::{check, DpdkError},
+    rte_ipv4_cksum, rte_ipv4_hdr, rte_ipv4_phdr_cksum, rte_ipv4_udptcp_cksum_mbuf, rte_ipv6_hdr, rte_ipv6_phdr_cksum,
+    rte_mbuf, rte_mbuf_set_tx_offload, rte_validate_tx_offload,
+};
+
+// `RTE_MBUF_F_TX_*`, named `PKT_TX_*` before DPDK 21.11. Their values have not changed.
+const RTE_MBUF_F_TX_TCP_SEG: u64 = 1 << 50;
+const RTE_MBUF_F_TX_TCP_CKSUM: u64 = 1 << 52;
+const RTE_MBUF_F_TX_UDP_CKSUM: u64 = 3 << 52;
+const RTE_MBUF_F_TX_L4_MASK: u64 = 3 << 52;
+const RTE_MBUF_F_TX_IP_CKSUM: u64 = 1 << 54;
+const RTE_MBUF_F_TX_IPV4: u64 = 1 << 55;
+const RTE_MBUF_F_TX_IPV6: u64 = 1 << 56;
+
+// `RTE_MBUF_F_RX_*`, named `PKT_RX_*` before DPDK 21.11. Each status takes two bits, neither set meaning unknown.
+const RTE_MBUF_F_RX_L4_CKSUM_BAD: u64 = 1 << 3;
+const RTE_MBUF_F_RX_IP_CKSUM_BAD: u64 = 1 << 4;
+const RTE_MBUF_F_RX_IP_CKSUM_GOOD: u64 = 1 << 7;
+const RTE_MBUF_F_RX_L4_CKSUM_GOOD: u64 = 1 << 8;
+
+/// Size of a UDP header, and of a TCP header without options.
+const UDP_HDR_LEN: usize = 8;
+const TCP_HDR_LEN: usize = 20;
+/// Size of an IPv4 header without options, and of an IPv6 header without extension headers.
+const IPV4_HDR_LEN: usize = 20;
+const IPV6_HDR_LEN: usize = 40;
+
+/// Transport protocol whose checksum the device computes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum L4Csum {
+    Tcp,
+    Udp,
+}
+
+impl L4Csum {
+    /// Offset of the checksum field in the transport header.
+    fn checksum_offset(self) -> usize {
+        match self {
+            L4Csum::Tcp => 16,
+            L4Csum::Udp => 6,
+        }
+    }
+}
+
+/// Checksums for the device to compute when sending a packet, along with the header lengths it needs to find them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxCsum {
+    /// Whether the packet is IPv4, in which case the device also computes the IP header checksum. Otherwise the
+    /// packet is IPv6.
+    pub ipv4: bool,
+    /// Transport checksum to compute, if any.
+    pub l4: Option<L4Csum>,
+    /// Length of the link-layer header, including VLAN tags.
+    pub l2_len: u16,
+    /// Length of the network header, including IPv4 options and IPv6 extension headers. At least 20 bytes for IPv4
+    /// and 40 for IPv6.
+    pub l3_len: u16,
+}
+
+/// Result of the checksum verification of one header by the device that received a packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumStatus {
+    /// The checksum is correct.
+    Good,
+    /// The checksum is wrong.
+    Bad,
+    /// The device did not check the checksum, which must be verified in software.
+    Unknown,
+    /// The checksum is not correct, but the integrity of the header is valid, e.g. because it was computed over data
+    /// that the device has since modified.
+    None,
+}
+
+impl ChecksumStatus {
+    fn from_flags(ol_flags: u64, good: u64, bad: u64) -> Self {
+        match (ol_flags & good != 0, ol_flags & bad != 0) {
+            (true, true) => ChecksumStatus::None,
+            (true, false) => ChecksumStatus::Good,
+            (false, true) => ChecksumStatus::Bad,
+            (false, false) => ChecksumStatus::Unknown,
+        }
+    }
+}
+
+/// Checksum verification of a received packet, per layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RxChecksumStatus {
+    /// IPv4 header checksum.
+    pub ip: ChecksumStatus,
+    /// TCP, UDP or SCTP checksum.
+    pub l4: ChecksumStatus,
+}
+
+impl Mbuf {
+    /// Whether the device that received this packet verified its checksums. Both are unknown unless the port was
+    /// configured with [`RxOffloads::CHECKSUM`](crate::ethdev::RxOffloads::CHECKSUM).
+    pub fn rx_checksum_status(&self) -> RxChecksumStatus {
+        let ol_flags = self.raw().ol_flags;
+        RxChecksumStatus {
+            ip: ChecksumStatus::from_flags(ol_flags, RTE_MBUF_F_RX_IP_CKSUM_GOOD, RTE_MBUF_F_RX_IP_CKSUM_BAD),
+            l4: ChecksumStatus::from_flags(ol_flags, RTE_MBUF_F_RX_L4_CKSUM_GOOD, RTE_MBUF_F_RX_L4_CKSUM_BAD),
+        }
+    }
+
+    /// Asks the device to compute the checksums described by `csum` when sending this packet.
+    ///
+    /// This sets the `RTE_MBUF_F_TX_*` flags and header lengths, clears the IPv4 header checksum and seeds the
+    /// transport checksum with that of the pseudo-header, as devices expect. The headers must be in the first segment,
+    /// and the port must have been configured with the matching [`TxOffloads`](crate::ethdev::TxOffloads).
+    ///
+    /// Fails with [`DpdkError::Inval`] if the header lengths are inconsistent, and with [`DpdkError::Busy`] if the data
+    /// is shared with another mbuf. The mbuf is left unchanged in both cases.
+    pub fn request_tx_checksum(&mut self, csum: TxCsum) -> Result<(), DpdkError> {
+        self.set_tx_offload(csum, None).map(|_| ())
+    }
+
+    /// Asks the device to split this TCP packet into segments carrying at most `mss` bytes of payload each, as with
+    /// [`Mbuf::request_tx_checksum`], which this implies.
+    ///
+    /// The port must have been configured with [`TxOffloads::TCP_TSO`](crate::ethdev::TxOffloads::TCP_TSO), and with
+    /// [`TxOffloads::MULTI_SEGS`](crate::ethdev::TxOffloads::MULTI_SEGS) if the packet is a chain. Use
+    /// [`Gso`](crate::ethdev::Gso) to fall back to software segmentation on devices without TSO. Fails as
+    /// [`Mbuf::request_tx_checksum`] does.
+    pub fn request_tso(&mut self, csum: TxCsum, mss: u16) -> Result<(), DpdkError> {
+        self.set_tx_offload(csum, Some(mss)).map(|_| ())
+    }
+
+    /// Sets the transmit offload metadata and returns the total length of the headers.
+    pub(crate) fn set_tx_offload(&mut self, csum: TxCsum, tso_segsz: Option<u16>) -> Result<usize, DpdkError> {
+        if tso_segsz.is_some() && csum.l4 != Some(L4Csum::Tcp) {
+            return Err(DpdkError::Inval);
+        }
+        let l2_len = csum.l2_len as usize;
+        let l3_len = csum.l3_len as usize;
+        // The IPv4 header checksum is cleared and the pseudo-header checksum reads the fixed part of the header.
+        if l3_len < if csum.ipv4 { IPV4_HDR_LEN } else { IPV6_HDR_LEN } {
+            return Err(DpdkError::Inval);
+        }
+        let l4_offset = l2_len + l3_len;
+        let data = self.try_data_mut().ok_or(MbufError::Shared)?;
+        let l4_len = match csum.l4 {
+            None => 0,
+            Some(L4Csum::Udp) => UDP_HDR_LEN,
+            // The data offset of the header, in 32-bit words.
+            Some(L4Csum::Tcp) => match data.get(l4_offset + 12) {
+                Some(&b) if (b >> 4) as usize * 4 >= TCP_HDR_LEN => (b >> 4) as usize * 4,
+                _ => TCP_HDR_LEN,
+            },
+        };
+        if l4_offset + l4_len > data.len() {
+            return Err(MbufError::TooShort {
+                requested: l4_offset + l4_len,
+                available: data.len(),
+            }
+            .into());
+        }
+
+        let mut ol_flags = self.raw().ol_flags
+            & !(RTE_MBUF_F_TX_TCP_SEG
+                | RTE_MBUF_F_TX_L4_MASK
+                | RTE_MBUF_F_TX_IP_CKSUM
+                | RTE_MBUF_F_TX_IPV4
+                | RTE_MBUF_F_TX_IPV6);
+        ol_flags |= if csum.ipv4 {
+            RTE_MBUF_F_TX_IPV4 | RTE_MBUF_F_TX_IP_CKSUM
+        } else {
+            RTE_MBUF_F_TX_IPV6
+        };
+        match csum.l4 {
+            Some(L4Csum::Tcp) => ol_flags |= RTE_MBUF_F_TX_TCP_CKSUM,
+            Some(L4Csum::Udp) => ol_flags |= RTE_MBUF_F_TX_UDP_CKSUM,
+            None => {},
+        }
+        if tso_segsz.is_some() {
+            ol_flags |= RTE_MBUF_F_TX_TCP_SEG;
+        }
+        // Validate the metadata on a copy of the mbuf header, so that the mbuf is left untouched on failure.
+        let mut shadow: rte_mbuf = *self.raw();
+        shadow.ol_flags = ol_flags;
+        unsafe {
+            rte_mbuf_set_tx_offload(
+                &mut shadow,
+                csum.l2_len,
+                csum.l3_len,
+                l4_len as u16,
+                tso_segsz.unwrap_or(0),
+            );
+            check(rte_validate_tx_offload(&shadow))?;
+            let m = self.as_mut_ptr();
+            (*m).ol_flags = ol_flags;
+            rte_mbuf_set_tx_offload(m, csum.l2_len, csum.l3_len, l4_len as u16, tso_segsz.unwrap_or(0));
+        }
+
+        // The data was found not to be shared above.
+        let data = self.data_mut();
+        if csum.ipv4 {
+            data[l2_len + 10..l2_len + 12].fill(0);
+        }
+        if let Some(l4) = csum.l4 {
+            // With TSO, the pseudo-header checksum leaves out the length, which differs in each segment.
+            let l3 = data[l2_len..].as_ptr();
+            let phdr_cksum = unsafe {
+                if csum.ipv4 {
+                    rte_ipv4_phdr_cksum(l3 as *const rte_ipv4_hdr, ol_flags)
+                } else {
+                    rte_ipv6_phdr_cksum(l3 as *const rte_ipv6_hdr, ol_flags)
+                }
+            };
+            let offset = l4_offset + l4.checksum_offset();
+            // The checksum is computed in network byte order, so it is stored as is.
+            data[offset..offset + 2].copy_from_slice(&phdr_cksum.to_ne_bytes());
+        }
+        Ok(l4_offset + l4_len)
+    }
+
+    /// Computes the IPv4 header and transport checksums of this packet in software, for devices without checksum
+    /// offload, and withdraws any checksum or segmentation request. The packet may span several segments.
+    pub(crate) fn fill_ipv4_checksums(&mut self, csum: TxCsum) -> Result<(), DpdkError> {
+        let l2_len = csum.l2_len as usize;
+        let l4_offset = l2_len + csum.l3_len as usize;
+        let l4_end = l4_offset + csum.l4.map_or(0, |l4| l4.checksum_offset() + 2);
+        if !csum.ipv4 || (csum.l3_len as usize) < IPV4_HDR_LEN || l4_end > self.data_len() {
+            return Err(DpdkError::Inval);
+        }
+
+        let data = self.try_data_mut().ok_or(MbufError::Shared)?;
+        data[l2_len + 10..l2_len + 12].fill(0);
+        let l3 = data[l2_len..].as_ptr() as *const rte_ipv4_hdr;
+        // Checksums are computed in network byte order, so they are stored as is.
+        let ip_cksum: u16 = unsafe { rte_ipv4_cksum(l3) };
+        data[l2_len + 10..l2_len + 12].copy_from_slice(&ip_cksum.to_ne_bytes());
+        if let Some(l4) = csum.l4 {
+            let offset = l4_offset + l4.checksum_offset();
+            data[offset..offset + 2].fill(0);
+            let l4_cksum: u16 = unsafe { rte_ipv4_udptcp_cksum_mbuf(self.as_ptr(), l3, l4_offset as u16) };
+            self.data_mut()[offset..offset + 2].copy_from_slice(&l4_cksum.to_ne_bytes());
+        }
+
+        let ol_flags = self.raw().ol_flags
+            & !(RTE_MBUF_F_TX_TCP_SEG
+                | RTE_MBUF_F_TX_L4_MASK
+                | RTE_MBUF_F_TX_IP_CKSUM
+                | RTE_MBUF_F_TX_IPV4
+                | RTE_MBUF_F_TX_IPV6);
+        unsafe { (*self.as_mut_ptr()).ol_flags = ol_flags };
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+mod batch;
+mod chain;
+#[cfg(feature = "ethdev")]
+mod checksum;
+mod ptype;
+mod shared;
+
+#[cfg(feature = "ethdev")]
+pub use self::checksum::{ChecksumStatus, L4Csum, RxChecksumStatus, TxCsum};
+pub use self::{
+    batch::{IntoIter, MbufBatch},
+    chain::{MbufChain, Segments, SegmentsMut},
+    ptype::{L2Type, L3Type, L4Type, PacketType, TunnelType},
+    shared::SharedMbuf,
+};
+
+use crate::{
+    error::DpdkError, mempool::PktMbufPool, rte_mbuf, rte_mbuf_refcnt_read, rte_pktmbuf_adj, rte_pktmbuf_alloc,
+    rte_pktmbuf_append, rte_pktmbuf_clone, rte_pktmbuf_free, rte_pktmbuf_headroom, rte_pktmbuf_prepend,
+    rte_pktmbuf_tailroom, rte_pktmbuf_trim,
+};
+use std::{error::Error, fmt, mem, os::raw::c_char, ptr::NonNull, slice};
+
+/// `RTE_MBUF_F_INDIRECT`: the mbuf is attached to the data buffer of another mbuf.
+const RTE_MBUF_F_INDIRECT: u64 = 1 << 62;
+
+/// Errors returned when resizing or writing the data of an [`Mbuf`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MbufError {
+    /// The first segment does not have enough headroom.
+    NoHeadroom { requested: usize, available: usize },
+    /// The last segment does not have enough tailroom.
+    NoTailroom { requested: usize, available: usize },
+    /// The segment does not hold enough data to be shortened by the requested amount.
+    TooShort { requested: usize, available: usize },
+    /// The requested range lies beyond the end of the packet.
+    OutOfBounds { offset: usize, len: usize, pkt_len: usize },
+    /// The data is shared with another mbuf and cannot be written.
+    Shared,
+}
+
+impl fmt::Display for MbufError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MbufError::NoHeadroom { requested, available } => {
+                write!(f, "requested {} bytes of headroom, {} available", requested, available)
+            },
+            MbufError::NoTailroom { requested, available } => {
+                write!(f, "requested {} bytes of tailroom, {} available", requested, available)
+            },
+            MbufError::TooShort { requested, available } => {
+                write!(
+                    f,
+                    "cannot remove {} bytes from a segment holding {}",
+                    requested, available
+                )
+            },
+            MbufError::OutOfBounds { offset, len, pkt_len } => write!(
+                f,
+                "range of {} bytes at offset {} exceeds packet length {}",
+                len, offset, pkt_len
+            ),
+            MbufError::Shared => write!(f, "the data is shared with another mbuf"),
+        }
+    }
+}
+
+impl Error for MbufError {}
+
+impl From<MbufError> for DpdkError {
+    fn from(e: MbufError) -> Self {
+        match e {
+            MbufError::NoHeadroom { .. } | MbufError::NoTailroom { .. } => DpdkError::NoSpc,
+            MbufError::TooShort { .. } | MbufError::OutOfBounds { .. } => DpdkError::Inval,
+            MbufError::Shared => DpdkError::Busy,
+        }
+    }
+}
+
+/// An owned packet buffer.
+///
+/// The underlying `rte_mbuf` is returned to its pool with `rte_pktmbuf_free` when this is dropped. Use
+/// [`Mbuf::into_raw`] and [`Mbuf::from_raw`] to hand the buffer over to code that manages it manually.
+#[repr(transparent)]
+pub struct Mbuf {
+    ptr: NonNull<rte_mbuf>,
+}
+
+// Mbufs are routinely handed over between lcores, and freeing them from any thread is supported by the mempool.
+unsafe impl Send for Mbuf {}
+
+impl Mbuf {
+    /// Allocates a new buffer from `pool`, returning `None` if the pool is exhausted.
+    pub fn alloc(pool: &PktMbufPool) -> Option<Self> {
+        NonNull::new(unsafe { rte_pktmbuf_alloc(pool.as_ptr()) }).map(|ptr| Mbuf { ptr })
+    }
+
+    /// Takes ownership of a raw `rte_mbuf`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, point to a valid mbuf and not be owned by anyone else.
+    pub unsafe fn from_raw(ptr: *mut rte_mbuf) -> Self {
+        debug_assert!(!ptr.is_null());
+        Mbuf {
+            ptr: NonNull::new_unchecked(ptr),
+        }
+    }
+
+    /// Releases ownership of the underlying `rte_mbuf` without freeing it.
+    pub fn into_raw(self) -> *mut rte_mbuf {
+        let ptr: *mut rte_mbuf = self.ptr.as_ptr();
+        mem::forget(self);
+        ptr
+    }
+
+    pub fn as_ptr(&self) -> *const rte_mbuf {
+        self.ptr.as_ptr()
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut rte_mbuf {
+        self.ptr.as_ptr()
+    }
+
+    fn raw(&self) -> &rte_mbuf {
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// Length of the data in this segment.
+    pub fn data_len(&self) -> usize {
+        self.raw().data_len as usize
+    }
+
+    /// Length of the whole packet, including any chained segments.
+    pub fn pkt_len(&self) -> usize {
+        self.raw().pkt_len as usize
+    }
+
+    /// Number of bytes available in front of the data.
+    pub fn headroom(&self) -> usize {
+        unsafe { rte_pktmbuf_headroom(self.as_ptr()) as usize }
+    }
+
+    /// Number of bytes available after the data.
+    pub fn tailroom(&self) -> usize {
+        unsafe { rte_pktmbuf_tailroom(self.as_ptr()) as usize }
+    }
+
+    /// Current value of the reference counter.
+    pub fn refcnt(&self) -> u16 {
+        unsafe { rte_mbuf_refcnt_read(self.as_ptr()) }
+    }
+
+    /// Data held in this segment.
+    pub fn data(&self) -> &[u8] {
+        let m: &rte_mbuf = self.raw();
+        unsafe {
+            let data: *const u8 = (m.buf_addr as *const u8).add(m.data_off as usize);
+            slice::from_raw_parts(data, m.data_len as usize)
+        }
+    }
+
+    /// Whether the data buffer of this segment may be visible through another mbuf, either because this mbuf has
+    /// been cloned or because it is itself a clone.
+    pub fn is_shared(&self) -> bool {
+        self.refcnt() > 1 || self.raw().ol_flags & RTE_MBUF_F_INDIRECT != 0
+    }
+
+    /// Creates a new packet that shares the data of this one, allocating indirect mbufs from `pool`. Returns `None`
+    /// if the pool is exhausted.
+    ///
+    /// Both packets are read-only while they are shared; see [`Mbuf::is_shared`].
+    pub fn clone_shallow(&self, pool: &PktMbufPool) -> Option<Mbuf> {
+        NonNull::new(unsafe { rte_pktmbuf_clone(self.ptr.as_ptr(), pool.as_ptr()) }).map(|ptr| Mbuf { ptr })
+    }
+
+    /// Mutable view of the data held in this segment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is shared with another mbuf. Use [`Mbuf::try_data_mut`] to handle that case.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        self.try_data_mut().expect("cannot mutate shared mbuf data")
+    }
+
+    /// Mutable view of the data held in this segment, or `None` if the data is shared with another mbuf.
+    pub fn try_data_mut(&mut self) -> Option<&mut [u8]> {
+        if self.is_shared() {
+            return None;
+        }
+        let m: &rte_mbuf = self.raw();
+        unsafe {
+            let data: *mut u8 = (m.buf_addr as *mut u8).add(m.data_off as usize);
+            Some(slice::from_raw_parts_mut(data, m.data_len as usize))
+        }
+    }
+
+    /// Last segment of the packet.
+    fn last_segment(&self) -> *mut rte_mbuf {
+        let mut m: *mut rte_mbuf = self.ptr.as_ptr();
+        unsafe {
+            while !(*m).next.is_null() {
+                m = (*m).next;
+            }
+        }
+        m
+    }
+
+    /// Grows the data of the first segment by `len` bytes at the front and returns the new bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is shared with another mbuf.
+    pub fn prepend(&mut self, len: usize) -> Result<&mut [u8], MbufError> {
+        assert!(!self.is_shared(), "cannot mutate shared mbuf data");
+        let available: usize = self.headroom();
+        if len > available {
+            return Err(MbufError::NoHeadroom {
+                requested: len,
+                available,
+            });
+        }
+        unsafe {
+            let data: *mut c_char = rte_pktmbuf_prepend(self.as_mut_ptr(), len as u16);
+            debug_assert!(!data.is_null());
+            Ok(slice::from_raw_parts_mut(data as *mut u8, len))
+        }
+    }
+
+    /// Grows the data of the last segment by `len` bytes at the back and returns the new bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is shared with another mbuf.
+    pub fn append(&mut self, len: usize) -> Result<&mut [u8], MbufError> {
+        assert!(!self.is_shared(), "cannot mutate shared mbuf data");
+        let available: usize = unsafe { rte_pktmbuf_tailroom(self.last_segment()) as usize };
+        if len > available {
+            return Err(MbufError::NoTailroom {
+                requested: len,
+                available,
+            });
+        }
+        unsafe {
+            let data: *mut c_char = rte_pktmbuf_append(self.as_mut_ptr(), len as u16);
+            debug_assert!(!data.is_null());
+            Ok(slice::from_raw_parts_mut(data as *mut u8, len))
+        }
+    }
+
+    /// Removes `len` bytes from the front of the first segment.
+    pub fn adj(&mut self, len: usize) -> Result<(), MbufError> {
+        let available: usize = self.data_len();
+        if len > available {
+            return Err(MbufError::TooShort {
+                requested: len,
+                available,
+            });
+        }
+        let data: *mut c_char = unsafe { rte_pktmbuf_adj(self.as_mut_ptr(), len as u16) };
+        debug_assert!(!data.is_null());
+        Ok(())
+    }
+
+    /// Removes `len` bytes from the back of the last segment.
+    pub fn trim(&mut self, len: usize) -> Result<(), MbufError> {
+        let available: usize = unsafe { (*self.last_segment()).data_len as usize };
+        if len > available || len > self.pkt_len() {
+            return Err(MbufError::TooShort {
+                requested: len,
+                available,
+            });
+        }
+        let ret = unsafe { rte_pktmbuf_trim(self.as_mut_ptr(), len as u16) };
+        debug_assert_eq!(ret, 0);
+        Ok(())
+    }
+}
+
+impl Drop for Mbuf {
+    fn drop(&mut self) {
+        unsafe { rte_pktmbuf_free(self.ptr.as_ptr()) }
+    }
+}
+
+impl fmt::Debug for Mbuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mbuf")
+            .field("ptr", &self.ptr)
+            .field("data_len", &self.data_len())
+            .field("pkt_len", &self.pkt_len())
+            .finish()
+    }
+}
@@ -0,0 +1,385 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use super::Mbuf;
+use crate::rte_mbuf_packet_type;
+use std::fmt;
+
+// Fields of `RTE_PTYPE_*`, which bindgen cannot evaluate. Each occupies four bits of `packet_type`.
+const L2_SHIFT: u32 = 0;
+const L3_SHIFT: u32 = 4;
+const L4_SHIFT: u32 = 8;
+const TUNNEL_SHIFT: u32 = 12;
+const INNER_L2_SHIFT: u32 = 16;
+const INNER_L3_SHIFT: u32 = 20;
+const INNER_L4_SHIFT: u32 = 24;
+
+/// Link-layer protocol, as in `RTE_PTYPE_L2_*` and `RTE_PTYPE_INNER_L2_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum L2Type {
+    Unknown,
+    Ether,
+    Timesync,
+    Arp,
+    Lldp,
+    Nsh,
+    EtherVlan,
+    EtherQinq,
+    Pppoe,
+    Fcoe,
+    Mpls,
+    /// A value that this crate does not know of.
+    Other(u8),
+}
+
+/// Network-layer protocol, as in `RTE_PTYPE_L3_*` and `RTE_PTYPE_INNER_L3_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum L3Type {
+    Unknown,
+    /// IPv4 without options.
+    Ipv4,
+    /// IPv4 with options.
+    Ipv4Ext,
+    /// IPv4, with or without options.
+    Ipv4ExtUnknown,
+    /// IPv6 without extension headers.
+    Ipv6,
+    /// IPv6 with extension headers.
+    Ipv6Ext,
+    /// IPv6, with or without extension headers.
+    Ipv6ExtUnknown,
+    /// A value that this crate does not know of.
+    Other(u8),
+}
+
+/// Transport-layer protocol, as in `RTE_PTYPE_L4_*` and `RTE_PTYPE_INNER_L4_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum L4Type {
+    Unknown,
+    Tcp,
+    Udp,
+    /// A fragment of an IP packet, whose transport header may be missing.
+    Frag,
+    Sctp,
+    Icmp,
+    /// An unfragmented IP packet of a protocol not listed here.
+    NonFrag,
+    Igmp,
+    /// A value that this crate does not know of.
+    Other(u8),
+}
+
+/// Tunneling protocol, as in `RTE_PTYPE_TUNNEL_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TunnelType {
+    None,
+    Ip,
+    Gre,
+    Vxlan,
+    Nvgre,
+    Geneve,
+    /// Any of the GRE-based tunnels carrying Ethernet frames.
+    Grenat,
+    Gtpc,
+    Gtpu,
+    Esp,
+    L2tp,
+    VxlanGpe,
+    MplsInGre,
+    MplsInUdp,
+    /// A value that this crate does not know of.
+    Other(u8),
+}
+
+/// Classification of a received packet by the device, as stored in the `packet_type` field of `rte_mbuf`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PacketType(u32);
+
+impl PacketType {
+    /// Wraps a raw `RTE_PTYPE_*` value.
+    pub fn from_raw(raw: u32) -> Self {
+        PacketType(raw)
+    }
+
+    /// Raw `RTE_PTYPE_*` value.
+    pub fn raw(self) -> u32 {
+        self.0
+    }
+
+    fn field(self, shift: u32) -> u8 {
+        (self.0 >> shift & 0xf) as u8
+    }
+
+    /// Outer link-layer protocol.
+    pub fn l2(self) -> L2Type {
+        match self.field(L2_SHIFT) {
+            0 => L2Type::Unknown,
+            1 => L2Type::Ether,
+            2 => L2Type::Timesync,
+            3 => L2Type::Arp,
+            4 => L2Type::Lldp,
+            5 => L2Type::Nsh,
+            6 => L2Type::EtherVlan,
+            7 => L2Type::EtherQinq,
+            8 => L2Type::Pppoe,
+            9 => L2Type::Fcoe,
+            10 => L2Type::Mpls,
+            v => L2Type::Other(v),
+        }
+    }
+
+    /// Outer network-layer protocol.
+    pub fn l3(self) -> L3Type {
+        // Unlike the inner values, the outer ones are not sequential: they encode the IP version and whether
+        // extensions are present as separate bits.
+        match self.field(L3_SHIFT) {
+            0x0 => L3Type::Unknown,
+            0x1 => L3Type::Ipv4,
+            0x3 => L3Type::Ipv4Ext,
+            0x4 => L3Type::Ipv6,
+            0x9 => L3Type::Ipv4ExtUnknown,
+            0xc => L3Type::Ipv6Ext,
+            0xe => L3Type::Ipv6ExtUnknown,
+            v => L3Type::Other(v),
+        }
+    }
+
+    /// Outer transport-layer protocol.
+    pub fn l4(self) -> L4Type {
+        l4_type(self.field(L4_SHIFT))
+    }
+
+    /// Tunneling protocol, if the packet is encapsulated.
+    pub fn tunnel(self) -> TunnelType {
+        match self.field(TUNNEL_SHIFT) {
+            0 => TunnelType::None,
+            1 => TunnelType::Ip,
+            2 => TunnelType::Gre,
+            3 => TunnelType::Vxlan,
+            4 => TunnelType::Nvgre,
+            5 => TunnelType::Geneve,
+            6 => TunnelType::Grenat,
+            7 => TunnelType::Gtpc,
+            8 => TunnelType::Gtpu,
+            9 => TunnelType::Esp,
+            10 => TunnelType::L2tp,
+            11 => TunnelType::VxlanGpe,
+            12 => TunnelType::MplsInGre,
+            13 => TunnelType::MplsInUdp,
+            v => TunnelType::Other(v),
+        }
+    }
+
+    /// Link-layer protocol of the encapsulated packet.
+    pub fn inner_l2(self) -> L2Type {
+        // The inner values are numbered differently from the outer ones.
+        match self.field(INNER_L2_SHIFT) {
+            0 => L2Type::Unknown,
+            1 => L2Type::Ether,
+            2 => L2Type::EtherVlan,
+            3 => L2Type::EtherQinq,
+            v => L2Type::Other(v),
+        }
+    }
+
+    /// Network-layer protocol of the encapsulated packet.
+    pub fn inner_l3(self) -> L3Type {
+        match self.field(INNER_L3_SHIFT) {
+            0 => L3Type::Unknown,
+            1 => L3Type::Ipv4,
+            2 => L3Type::Ipv4Ext,
+            3 => L3Type::Ipv6,
+            4 => L3Type::Ipv4ExtUnknown,
+            5 => L3Type::Ipv6Ext,
+            6 => L3Type::Ipv6ExtUnknown,
+            v => L3Type::Other(v),
+        }
+    }
+
+    /// Transport-layer protocol of the encapsulated packet.
+    pub fn inner_l4(self) -> L4Type {
+        l4_type(self.field(INNER_L4_SHIFT))
+    }
+}
+
+fn l4_type(v: u8) -> L4Type {
+    match v {
+        0 => L4Type::Unknown,
+        1 => L4Type::Tcp,
+        2 => L4Type::Udp,
+        3 => L4Type::Frag,
+        4 => L4Type::Sctp,
+        5 => L4Type::Icmp,
+        6 => L4Type::NonFrag,
+        7 => L4Type::Igmp,
+        v => L4Type::Other(v),
+    }
+}
+
+impl fmt::Debug for PacketType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PacketType")
+            .field("l2", &self.l2())
+            .field("l3", &self.l3())
+            .field("l4", &self.l4())
+            .field("tunnel", &self.tunnel())
+            .field("inner_l2", &self.inner_l2())
+            .field("inner_l3", &self.inner_l3())
+            .field("inner_l4", &self.inner_l4())
+            .finish()
+    }
+}
+
+impl Mbuf {
+    /// Classification of this packet by the device that received it. All fields are unknown unless the device
+    /// supports them; see [`EthPort::supported_ptypes`](crate::ethdev::EthPort::supported_ptypes).
+    pub fn packet_type(&self) -> PacketType {
+        PacketType(unsafe { rte_mbuf_packet_type(self.as_ptr()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Values of the `RTE_PTYPE_*` macros in rte_mbuf_ptype.h.
+    const RTE_PTYPE_L2_ETHER: u32 = 0x0000_0001;
+    const RTE_PTYPE_L2_ETHER_VLAN: u32 = 0x0000_0006;
+    const RTE_PTYPE_L2_ETHER_QINQ: u32 = 0x0000_0007;
+    const RTE_PTYPE_L2_ETHER_MPLS: u32 = 0x0000_000a;
+    const RTE_PTYPE_L3_IPV4: u32 = 0x0000_0010;
+    const RTE_PTYPE_L3_IPV4_EXT: u32 = 0x0000_0030;
+    const RTE_PTYPE_L3_IPV6: u32 = 0x0000_0040;
+    const RTE_PTYPE_L3_IPV4_EXT_UNKNOWN: u32 = 0x0000_0090;
+    const RTE_PTYPE_L3_IPV6_EXT: u32 = 0x0000_00c0;
+    const RTE_PTYPE_L3_IPV6_EXT_UNKNOWN: u32 = 0x0000_00e0;
+    const RTE_PTYPE_L4_TCP: u32 = 0x0000_0100;
+    const RTE_PTYPE_L4_UDP: u32 = 0x0000_0200;
+    const RTE_PTYPE_L4_FRAG: u32 = 0x0000_0300;
+    const RTE_PTYPE_L4_NONFRAG: u32 = 0x0000_0600;
+    const RTE_PTYPE_TUNNEL_VXLAN: u32 = 0x0000_3000;
+    const RTE_PTYPE_TUNNEL_GENEVE: u32 = 0x0000_5000;
+    const RTE_PTYPE_TUNNEL_MPLS_IN_UDP: u32 = 0x0000_d000;
+    const RTE_PTYPE_INNER_L2_ETHER: u32 = 0x0001_0000;
+    const RTE_PTYPE_INNER_L2_ETHER_VLAN: u32 = 0x0002_0000;
+    const RTE_PTYPE_INNER_L3_IPV4: u32 = 0x0010_0000;
+    const RTE_PTYPE_INNER_L3_IPV4_EXT: u32 = 0x0020_0000;
+    const RTE_PTYPE_INNER_L3_IPV6: u32 = 0x0030_0000;
+    const RTE_PTYPE_INNER_L3_IPV4_EXT_UNKNOWN: u32 = 0x0040_0000;
+    const RTE_PTYPE_INNER_L3_IPV6_EXT: u32 = 0x0050_0000;
+    const RTE_PTYPE_INNER_L3_IPV6_EXT_UNKNOWN: u32 = 0x0060_0000;
+    const RTE_PTYPE_INNER_L4_TCP: u32 = 0x0100_0000;
+    const RTE_PTYPE_INNER_L4_UDP: u32 = 0x0200_0000;
+    const RTE_PTYPE_INNER_L4_NONFRAG: u32 = 0x0600_0000;
+
+    #[test]
+    fn unknown() {
+        let ptype = PacketType::from_raw(0);
+        assert_eq!(ptype.l2(), L2Type::Unknown);
+        assert_eq!(ptype.l3(), L3Type::Unknown);
+        assert_eq!(ptype.l4(), L4Type::Unknown);
+        assert_eq!(ptype.tunnel(), TunnelType::None);
+        assert_eq!(ptype.inner_l2(), L2Type::Unknown);
+        assert_eq!(ptype.inner_l3(), L3Type::Unknown);
+        assert_eq!(ptype.inner_l4(), L4Type::Unknown);
+    }
+
+    #[test]
+    fn outer_l3() {
+        let cases = [
+            (RTE_PTYPE_L3_IPV4, L3Type::Ipv4),
+            (RTE_PTYPE_L3_IPV4_EXT, L3Type::Ipv4Ext),
+            (RTE_PTYPE_L3_IPV6, L3Type::Ipv6),
+            (RTE_PTYPE_L3_IPV4_EXT_UNKNOWN, L3Type::Ipv4ExtUnknown),
+            (RTE_PTYPE_L3_IPV6_EXT, L3Type::Ipv6Ext),
+            (RTE_PTYPE_L3_IPV6_EXT_UNKNOWN, L3Type::Ipv6ExtUnknown),
+            (0x0000_0020, L3Type::Other(2)),
+        ];
+        for (raw, l3) in cases {
+            assert_eq!(PacketType::from_raw(raw).l3(), l3, "{:#x}", raw);
+        }
+    }
+
+    #[test]
+    fn inner_l3() {
+        let cases = [
+            (RTE_PTYPE_INNER_L3_IPV4, L3Type::Ipv4),
+            (RTE_PTYPE_INNER_L3_IPV4_EXT, L3Type::Ipv4Ext),
+            (RTE_PTYPE_INNER_L3_IPV6, L3Type::Ipv6),
+            (RTE_PTYPE_INNER_L3_IPV4_EXT_UNKNOWN, L3Type::Ipv4ExtUnknown),
+            (RTE_PTYPE_INNER_L3_IPV6_EXT, L3Type::Ipv6Ext),
+            (RTE_PTYPE_INNER_L3_IPV6_EXT_UNKNOWN, L3Type::Ipv6ExtUnknown),
+            (0x0090_0000, L3Type::Other(9)),
+        ];
+        for (raw, l3) in cases {
+            assert_eq!(PacketType::from_raw(raw).inner_l3(), l3, "{:#x}", raw);
+        }
+    }
+
+    #[test]
+    fn l2() {
+        assert_eq!(PacketType::from_raw(RTE_PTYPE_L2_ETHER).l2(), L2Type::Ether);
+        assert_eq!(PacketType::from_raw(RTE_PTYPE_L2_ETHER_VLAN).l2(), L2Type::EtherVlan);
+        assert_eq!(PacketType::from_raw(RTE_PTYPE_L2_ETHER_QINQ).l2(), L2Type::EtherQinq);
+        assert_eq!(PacketType::from_raw(RTE_PTYPE_L2_ETHER_MPLS).l2(), L2Type::Mpls);
+        assert_eq!(PacketType::from_raw(RTE_PTYPE_INNER_L2_ETHER).inner_l2(), L2Type::Ether);
+        assert_eq!(
+            PacketType::from_raw(RTE_PTYPE_INNER_L2_ETHER_VLAN).inner_l2(),
+            L2Type::EtherVlan
+        );
+    }
+
+    #[test]
+    fn ipv4_tcp() {
+        let ptype = PacketType::from_raw(RTE_PTYPE_L2_ETHER | RTE_PTYPE_L3_IPV4_EXT_UNKNOWN | RTE_PTYPE_L4_TCP);
+        assert_eq!(ptype.l2(), L2Type::Ether);
+        assert_eq!(ptype.l3(), L3Type::Ipv4ExtUnknown);
+        assert_eq!(ptype.l4(), L4Type::Tcp);
+        assert_eq!(ptype.tunnel(), TunnelType::None);
+    }
+
+    #[test]
+    fn ipv6_fragment() {
+        let ptype = PacketType::from_raw(RTE_PTYPE_L2_ETHER | RTE_PTYPE_L3_IPV6_EXT | RTE_PTYPE_L4_FRAG);
+        assert_eq!(ptype.l3(), L3Type::Ipv6Ext);
+        assert_eq!(ptype.l4(), L4Type::Frag);
+    }
+
+    #[test]
+    fn tunnels() {
+        let vxlan = PacketType::from_raw(
+            RTE_PTYPE_L2_ETHER
+                | RTE_PTYPE_L3_IPV4
+                | RTE_PTYPE_L4_UDP
+                | RTE_PTYPE_TUNNEL_VXLAN
+                | RTE_PTYPE_INNER_L2_ETHER
+                | RTE_PTYPE_INNER_L3_IPV6
+                | RTE_PTYPE_INNER_L4_TCP,
+        );
+        assert_eq!(vxlan.l3(), L3Type::Ipv4);
+        assert_eq!(vxlan.l4(), L4Type::Udp);
+        assert_eq!(vxlan.tunnel(), TunnelType::Vxlan);
+        assert_eq!(vxlan.inner_l2(), L2Type::Ether);
+        assert_eq!(vxlan.inner_l3(), L3Type::Ipv6);
+        assert_eq!(vxlan.inner_l4(), L4Type::Tcp);
+
+        let geneve = PacketType::from_raw(
+            RTE_PTYPE_L3_IPV6 | RTE_PTYPE_L4_NONFRAG | RTE_PTYPE_TUNNEL_GENEVE | RTE_PTYPE_INNER_L4_UDP,
+        );
+        assert_eq!(geneve.l3(), L3Type::Ipv6);
+        assert_eq!(geneve.l4(), L4Type::NonFrag);
+        assert_eq!(geneve.tunnel(), TunnelType::Geneve);
+        assert_eq!(geneve.inner_l4(), L4Type::Udp);
+
+        let mpls = PacketType::from_raw(RTE_PTYPE_TUNNEL_MPLS_IN_UDP | RTE_PTYPE_INNER_L4_NONFRAG);
+        assert_eq!(mpls.tunnel(), TunnelType::MplsInUdp);
+        assert_eq!(mpls.inner_l4(), L4Type::NonFrag);
+        assert_eq!(PacketType::from_raw(0x0000_e000).tunnel(), TunnelType::Other(14));
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        let raw = RTE_PTYPE_L2_ETHER | RTE_PTYPE_L3_IPV6 | RTE_PTYPE_L4_UDP;
+        assert_eq!(PacketType::from_raw(raw).raw(), raw);
+    }
+}
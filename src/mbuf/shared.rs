@@ -0,0 +1,80 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use super::Mbuf;
+use crate::{rte_mbuf, rte_pktmbuf_refcnt_update};
+use std::{fmt, ops::Deref};
+
+/// A reference-counted packet buffer.
+///
+/// Cloning bumps the reference counter of every segment with `rte_pktmbuf_refcnt_update` instead of copying any
+/// data, and dropping a clone decrements it again through `rte_pktmbuf_free`. This is what TCP needs to keep a segment
+/// around for retransmission while it is also queued for transmission. The data can only be modified once all other
+/// references are gone.
+pub struct SharedMbuf {
+    mbuf: Mbuf,
+}
+
+impl SharedMbuf {
+    pub fn new(mbuf: Mbuf) -> Self {
+        SharedMbuf { mbuf }
+    }
+
+    /// Whether this is the only reference to the packet.
+    pub fn is_unique(&self) -> bool {
+        self.mbuf.refcnt() == 1
+    }
+
+    /// Returns mutable access to the packet if this is the only reference to it.
+    pub fn get_mut(&mut self) -> Option<&mut Mbuf> {
+        if self.is_unique() {
+            Some(&mut self.mbuf)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the packet if this is the only reference to it, or the shared packet otherwise.
+    pub fn try_unwrap(self) -> Result<Mbuf, SharedMbuf> {
+        if self.is_unique() {
+            Ok(self.mbuf)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Clone for SharedMbuf {
+    fn clone(&self) -> Self {
+        let ptr: *mut rte_mbuf = self.mbuf.as_ptr() as *mut rte_mbuf;
+        unsafe {
+            rte_pktmbuf_refcnt_update(ptr, 1);
+            SharedMbuf {
+                mbuf: Mbuf::from_raw(ptr),
+            }
+        }
+    }
+}
+
+impl Deref for SharedMbuf {
+    type Target = Mbuf;
+
+    fn deref(&self) -> &Mbuf {
+        &self.mbuf
+    }
+}
+
+impl From<Mbuf> for SharedMbuf {
+    fn from(mbuf: Mbuf) -> Self {
+        SharedMbuf::new(mbuf)
+    }
+}
+
+impl fmt::Debug for SharedMbuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedMbuf")
+            .field("mbuf", &self.mbuf)
+            .field("refcnt", &self.mbuf.refcnt())
+            .finish()
+    }
+}
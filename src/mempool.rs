@@ -0,0 +1,183 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+use crate::{
+    error::{check, check_ptr, DpdkError},
+    mbuf::{Mbuf, MbufBatch},
+    rte_mempool, rte_mempool_avail_count, rte_mempool_free, rte_mempool_get_bulk, rte_mempool_in_use_count,
+    rte_mempool_put_bulk, rte_pktmbuf_alloc_bulk, rte_pktmbuf_pool_create, rte_socket_id, RTE_MBUF_DEFAULT_BUF_SIZE,
+};
+use std::{
+    ffi::{CStr, CString},
+    fmt,
+    os::raw::{c_int, c_uint, c_void},
+    ptr::NonNull,
+};
+
+/// A pool of packet buffers created with `rte_pktmbuf_pool_create`.
+///
+/// The pool is released with `rte_mempool_free` when dropped. If any buffers allocated from it are still in use at
+/// that point, the pool is leaked instead so that those buffers remain valid.
+pub struct PktMbufPool {
+    ptr: NonNull<rte_mempool>,
+}
+
+// Mempool operations are thread-safe unless the pool was created with single-producer/consumer flags, which
+// `rte_pktmbuf_pool_create` never does.
+unsafe impl Send for PktMbufPool {}
+unsafe impl Sync for PktMbufPool {}
+
+impl PktMbufPool {
+    /// Starts building a pool with the given name, which must be unique in the process.
+    pub fn builder(name: impl Into<String>) -> PktMbufPoolBuilder {
+        PktMbufPoolBuilder {
+            name: name.into(),
+            count: 8191,
+            cache_size: 250,
+            priv_size: 0,
+            data_room_size: RTE_MBUF_DEFAULT_BUF_SIZE as u16,
+            socket_id: None,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut rte_mempool {
+        self.ptr.as_ptr()
+    }
+
+    /// Name of the pool.
+    pub fn name(&self) -> String {
+        unsafe { CStr::from_ptr(self.ptr.as_ref().name.as_ptr()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Number of buffers available for allocation, including those held in per-lcore caches.
+    pub fn avail_count(&self) -> usize {
+        unsafe { rte_mempool_avail_count(self.as_ptr()) as usize }
+    }
+
+    /// Number of buffers currently allocated.
+    pub fn in_use_count(&self) -> usize {
+        unsafe { rte_mempool_in_use_count(self.as_ptr()) as usize }
+    }
+
+    /// Allocates a buffer, returning `None` if the pool is exhausted.
+    pub fn alloc(&self) -> Option<Mbuf> {
+        Mbuf::alloc(self)
+    }
+
+    /// Allocates `count` buffers with a single call to `rte_pktmbuf_alloc_bulk`. Either all buffers are allocated or
+    /// none is, in which case [`DpdkError::NoEnt`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` exceeds the capacity `N` of the batch.
+    pub fn alloc_bulk<const N: usize>(&self, count: usize) -> Result<MbufBatch<N>, DpdkError> {
+        assert!(count <= N, "cannot allocate {} mbufs into a batch of {}", count, N);
+        let mut batch: MbufBatch<N> = MbufBatch::new();
+        unsafe {
+            check(rte_pktmbuf_alloc_bulk(
+                self.as_ptr(),
+                batch.as_mut_ptr(),
+                count as c_uint,
+            ))?;
+            batch.set_len(count);
+        }
+        Ok(batch)
+    }
+
+    /// Takes `objs.len()` raw objects out of the pool with `rte_mempool_get_bulk`. Unlike [`PktMbufPool::alloc_bulk`],
+    /// the objects are not reset to empty mbufs. Either all objects are retrieved or none is, in which case
+    /// [`DpdkError::NoEnt`] is returned.
+    pub fn get_bulk(&self, objs: &mut [*mut c_void]) -> Result<(), DpdkError> {
+        check(unsafe { rte_mempool_get_bulk(self.as_ptr(), objs.as_mut_ptr(), objs.len() as c_uint) })?;
+        Ok(())
+    }
+
+    /// Returns raw objects to the pool with `rte_mempool_put_bulk`.
+    ///
+    /// # Safety
+    ///
+    /// Every object must have been taken out of this pool and must not be used afterwards.
+    pub unsafe fn put_bulk(&self, objs: &[*mut c_void]) {
+        rte_mempool_put_bulk(self.as_ptr(), objs.as_ptr(), objs.len() as c_uint)
+    }
+}
+
+impl Drop for PktMbufPool {
+    fn drop(&mut self) {
+        if self.in_use_count() == 0 {
+            unsafe { rte_mempool_free(self.as_ptr()) }
+        }
+    }
+}
+
+impl fmt::Debug for PktMbufPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PktMbufPool")
+            .field("name", &self.name())
+            .field("avail_count", &self.avail_count())
+            .field("in_use_count", &self.in_use_count())
+            .finish()
+    }
+}
+
+/// Builder for [`PktMbufPool`].
+#[derive(Debug, Clone)]
+pub struct PktMbufPoolBuilder {
+    name: String,
+    count: u32,
+    cache_size: u32,
+    priv_size: u16,
+    data_room_size: u16,
+    socket_id: Option<c_int>,
+}
+
+impl PktMbufPoolBuilder {
+    /// Number of buffers in the pool. The optimum is a power of two minus one.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Number of buffers kept in each per-lcore cache.
+    pub fn cache_size(mut self, cache_size: u32) -> Self {
+        self.cache_size = cache_size;
+        self
+    }
+
+    /// Size of the application private area between the `rte_mbuf` structure and the data buffer.
+    pub fn priv_size(mut self, priv_size: u16) -> Self {
+        self.priv_size = priv_size;
+        self
+    }
+
+    /// Size of the data buffer of each mbuf, including `RTE_PKTMBUF_HEADROOM`.
+    pub fn data_room_size(mut self, data_room_size: u16) -> Self {
+        self.data_room_size = data_room_size;
+        self
+    }
+
+    /// NUMA socket to allocate memory from. Defaults to the socket of the calling lcore.
+    pub fn socket_id(mut self, socket_id: c_int) -> Self {
+        self.socket_id = Some(socket_id);
+        self
+    }
+
+    /// Creates the pool. Fails with [`DpdkError::Exist`] if a pool with the same name already exists.
+    pub fn build(self) -> Result<PktMbufPool, DpdkError> {
+        let name: CString = CString::new(self.name).map_err(|_| DpdkError::Inval)?;
+        let socket_id: c_int = self.socket_id.unwrap_or_else(|| unsafe { rte_socket_id() as c_int });
+        let ptr: *mut rte_mempool = unsafe {
+            rte_pktmbuf_pool_create(
+                name.as_ptr(),
+                self.count,
+                self.cache_size,
+                self.priv_size,
+                self.data_room_size,
+                socket_id,
+            )
+        };
+        Ok(PktMbufPool { ptr: check_ptr(ptr)? })
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+//! Smoke test of the EAL. It needs DPDK with the `net_null` driver at run time, so it is ignored by default; run it
+//! with `make test-eal`.
+
+use dpdk_rs::{eal::Eal, load_drivers, rte_eth_dev_count_avail};
+
+#[test]
+#[ignore]
+fn init_null_vdev() {
+    load_drivers();
+    let eal: Eal = Eal::init(["eal-smoke", "--no-huge", "--no-pci", "--vdev=net_null0"])
+        .unwrap_or_else(|e| panic!("Failed to initialize EAL: {}", e));
+    assert_eq!(unsafe { rte_eth_dev_count_avail() }, 1);
+    drop(eal);
+}
@@ -4,12 +4,47 @@
  */
 
 #include <rte_build_config.h>
-#include <rte_ethdev.h>
 #include <rte_common.h>
 #include <rte_cycles.h>
 #include <rte_eal.h>
-#include <rte_ip.h>
+#include <rte_errno.h>
 #include <rte_lcore.h>
 #include <rte_memcpy.h>
-#include <rte_udp.h>
+
+/* Headers of the DPDK libraries enabled through cargo features. */
+
+#ifdef DPDK_RS_MEMPOOL
+#include <rte_mempool.h>
 #include <rte_mbuf.h>
+#endif
+
+#ifdef DPDK_RS_ETHDEV
+#include <rte_ethdev.h>
+#include <rte_ip.h>
+#include <rte_tcp.h>
+#include <rte_udp.h>
+#endif
+
+#ifdef DPDK_RS_RING
+#include <rte_ring.h>
+#endif
+
+#ifdef DPDK_RS_FLOW
+#include <rte_flow.h>
+#endif
+
+#ifdef DPDK_RS_HASH
+#include <rte_hash.h>
+#endif
+
+#ifdef DPDK_RS_TIMER
+#include <rte_timer.h>
+#endif
+
+#ifdef DPDK_RS_CRYPTODEV
+#include <rte_cryptodev.h>
+#endif
+
+#ifdef DPDK_RS_GSO
+#include <rte_gso.h>
+#endif